@@ -0,0 +1,706 @@
+// Copyright 2025 shawn@hurley.page
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests of `find-node` queries, which index a few small sources into a temporary database and
+//! check the incidents found in them.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::sync::OnceLock;
+use tree_sitter_stack_graphs::loader::LanguageConfiguration;
+use tree_sitter_stack_graphs::NoCancellation;
+use tree_sitter_stack_graphs_c_sharp::cli::find_node::FindNodeArgs;
+use tree_sitter_stack_graphs_c_sharp::cli::index::IndexArgs;
+use tree_sitter_stack_graphs_c_sharp::cli::index_external::IndexExternalArgs;
+use tree_sitter_stack_graphs_c_sharp::cli::query::NodeType;
+use tree_sitter_stack_graphs_c_sharp::cli::results::OutputFormat;
+use tree_sitter_stack_graphs_c_sharp::cli::results::Result;
+use tree_sitter_stack_graphs_c_sharp::cli::results::Variable;
+use url::Url;
+
+/// A temporary directory with sources and the database they are indexed into.
+struct Workspace {
+    dir: tempfile::TempDir,
+}
+
+impl Workspace {
+    /// Writes `sources`, pairs of a path relative to the workspace and the text of the file.
+    fn new(sources: &[(&str, &str)]) -> Workspace {
+        let dir = tempfile::tempdir().expect("failed to create temporary directory");
+        for (path, text) in sources {
+            let path = dir.path().join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, text).unwrap();
+        }
+        Workspace { dir }
+    }
+
+    fn root(&self) -> PathBuf {
+        self.dir.path().canonicalize().unwrap()
+    }
+
+    fn db_path(&self) -> PathBuf {
+        self.root().join("index.db")
+    }
+
+    /// Indexes the given paths of the workspace, for every framework or for one.
+    fn index(&self, paths: &[&str], framework: Option<&str>) {
+        self.index_with(self.index_args(paths), framework);
+    }
+
+    /// The arguments of the `index` command for the given paths of the workspace.
+    fn index_args(&self, paths: &[&str]) -> IndexArgs {
+        IndexArgs {
+            source_paths: paths.iter().map(|path| self.root().join(path)).collect(),
+            force: false,
+            verbose: false,
+            continue_from: None,
+            hide_error_details: false,
+            max_file_time: None,
+            stats: false,
+        }
+    }
+
+    fn index_with(&self, index_args: IndexArgs, framework: Option<&str>) {
+        index_args.run(&self.db_path(), &language_configuration(framework)[0]).expect("failed to index");
+    }
+
+    /// Indexes the given paths of the workspace as the external file set `name`.
+    fn index_external(&self, name: &str, paths: &[&str]) {
+        let index_external_args = IndexExternalArgs {
+            name: name.to_string(),
+            paths: paths.iter().map(|path| self.root().join(path)).collect(),
+        };
+        index_external_args.run(&self.db_path(), &language_configuration(None)[0]).expect("failed to index external files");
+    }
+
+    /// Runs a query and returns the incidents found as `PATH:LINE:CHARACTER`, with the path
+    /// relative to the workspace, the line 1-based and the character 0-based.
+    fn find(&self, node_type: NodeType, query: &str, framework: Option<&str>) -> Vec<String> {
+        self.results(node_type, query, framework).iter()
+            .map(|result| {
+                let path = Url::parse(&result.file_uri).unwrap().to_file_path().unwrap();
+                let path = path.strip_prefix(self.root()).unwrap_or(&path).to_path_buf();
+                format!("{}:{}:{}", path.display(), result.line_number, result.code_location.start_position.character)
+            })
+            .collect()
+    }
+
+    /// Runs a query and returns the incidents found.
+    fn results(&self, node_type: NodeType, query: &str, framework: Option<&str>) -> Vec<Result> {
+        let find_node_args = FindNodeArgs {
+            node_type,
+            regex: query.to_string(),
+            output: OutputFormat::Text,
+        };
+        find_node_args.run(&self.db_path(), language_configuration(framework)).expect("failed to query")
+    }
+}
+
+/// The language configuration for every framework or for one. Building the builtins takes a
+/// while, so every configuration is built once and shared by the tests.
+fn language_configuration(framework: Option<&str>) -> &'static [LanguageConfiguration] {
+    static CONFIGURATIONS: OnceLock<Mutex<HashMap<Option<String>, &'static [LanguageConfiguration]>>> = OnceLock::new();
+    let mut configurations = CONFIGURATIONS.get_or_init(Default::default).lock().unwrap();
+    configurations.entry(framework.map(str::to_string)).or_insert_with(|| {
+        let lc = match framework {
+            Some(framework) => tree_sitter_stack_graphs_c_sharp::try_language_configuration_for_framework(framework, &NoCancellation),
+            None => tree_sitter_stack_graphs_c_sharp::try_language_configuration(&NoCancellation),
+        };
+        let lc = lc.unwrap_or_else(|err| panic!("{}", err.display_pretty()));
+        Box::leak(Box::new([lc]))
+    })
+}
+
+/// Indexes `sources` and runs a query against them.
+fn find(sources: &[(&str, &str)], node_type: NodeType, query: &str) -> Vec<String> {
+    let workspace = Workspace::new(sources);
+    workspace.index(&["."], None);
+    workspace.find(node_type, query, None)
+}
+
+#[test]
+fn type_references_in_declarations() {
+    let source = r#"namespace Shop
+{
+    class Money { }
+    class Item { }
+    class Handler { }
+
+    delegate Money Pricing(Item item);
+
+    class Cart<T> where T : Item
+    {
+        Money Total { get; set; }
+        event Handler Changed { add { } remove { } }
+        Item this[int index] => null;
+        public static Money operator +(Cart<T> cart, Money money) => money;
+        public static explicit operator Money(Cart<T> cart) => null;
+
+        void Run()
+        {
+            Money Sum(Item item) => null;
+        }
+        Money[] totals;
+        Money[] All(Money[] input, Item[][] nested) => input;
+        (Money, int) Pair((Item first, Money second) pair) => default;
+        ref Money First(ref Money money) => ref money;
+        Money?[] maybe;
+        System.Collections.Generic.List<Money[]> lists;
+        Money[] Make() => new Money[3];
+    }
+}
+"#;
+    let sources = [("Shop.cs", source)];
+    assert_eq!(
+        find(&sources, NodeType::TypeReference, "Shop.Money"),
+        vec![
+            "Shop.cs:7:13", "Shop.cs:11:8", "Shop.cs:14:22", "Shop.cs:14:53", "Shop.cs:15:40", "Shop.cs:19:12",
+            "Shop.cs:21:8", "Shop.cs:22:8", "Shop.cs:22:20", "Shop.cs:23:9", "Shop.cs:23:39", "Shop.cs:24:12",
+            "Shop.cs:24:28", "Shop.cs:25:8", "Shop.cs:26:40", "Shop.cs:27:8", "Shop.cs:27:30",
+        ],
+    );
+    assert_eq!(
+        find(&sources, NodeType::TypeReference, "Shop.Item"),
+        vec!["Shop.cs:7:27", "Shop.cs:9:28", "Shop.cs:13:8", "Shop.cs:19:22", "Shop.cs:22:35", "Shop.cs:23:27"],
+    );
+    assert_eq!(
+        find(&sources, NodeType::TypeReference, "Shop.Handler"),
+        vec!["Shop.cs:12:14"],
+    );
+}
+
+#[test]
+fn invocations_of_overloads() {
+    let source = r#"namespace A
+{
+    class C
+    {
+        public static void N(bool first, int second) { }
+        public static void N(string text) { }
+        public static void N(int count, params string[] names) { }
+        public static void M(System.Func<int, bool> predicate, int limit) { }
+
+        void Run(int x, int y)
+        {
+            C.N(x < 1, y >> 2);
+            C.N("text");
+            C.N(text: "text");
+            C.N(1, "a", "b");
+            C.N(2UL);
+            C.N(nameof(Run));
+            C.N(new System.String('a', 1));
+            C.N(1, $"{x}");
+            M(v => v > 3, y);
+            M(v => v < 3 && v > 1, (int)'c');
+        }
+    }
+}
+"#;
+    let sources = [("A.cs", source)];
+    assert_eq!(
+        find(&sources, NodeType::Invocation, "A.C.N"),
+        vec!["A.cs:12:14", "A.cs:13:14", "A.cs:14:14", "A.cs:15:14", "A.cs:16:14", "A.cs:17:14", "A.cs:18:14", "A.cs:19:14"],
+    );
+    assert_eq!(
+        find(&sources, NodeType::Invocation, "A.C.N(bool, int)"),
+        vec!["A.cs:12:14"],
+    );
+    assert_eq!(
+        find(&sources, NodeType::Invocation, "A.C.N(System.String)"),
+        vec!["A.cs:13:14", "A.cs:14:14", "A.cs:17:14", "A.cs:18:14"],
+    );
+    assert_eq!(
+        find(&sources, NodeType::Invocation, "A.C.N(int, string[])"),
+        vec!["A.cs:14:14", "A.cs:15:14", "A.cs:19:14"],
+    );
+    assert_eq!(
+        find(&sources, NodeType::Method, "A.C.M(Func<int,bool>, int)"),
+        vec!["A.cs:20:12", "A.cs:21:12"],
+    );
+}
+
+#[test]
+fn extension_method_invocations() {
+    let source = r#"using System.Collections.Generic;
+using System.Linq;
+
+namespace App
+{
+    class Program
+    {
+        void Run(List<int> numbers)
+        {
+            var large = numbers.Where(n => n > 1).ToList();
+            var any = Enumerable.Any(numbers);
+            var count = numbers.Count;
+        }
+    }
+}
+"#;
+    let sources = [("Program.cs", source)];
+    assert_eq!(
+        find(&sources, NodeType::Invocation, "System.Linq.Enumerable.*"),
+        vec!["Program.cs:10:32", "Program.cs:10:50", "Program.cs:11:33"],
+    );
+    assert_eq!(
+        find(&sources, NodeType::Method, "System.Linq.Enumerable.Count"),
+        Vec::<String>::new(),
+    );
+}
+
+#[test]
+fn derived_types() {
+    let shapes = r#"namespace Shapes
+{
+    interface IShape { double Area(); }
+    interface ISolid : IShape { }
+
+    abstract class Shape : IShape
+    {
+        public abstract double Area();
+        protected void Log() { }
+    }
+
+    class Circle : Shape
+    {
+        public override double Area() { this.Log(); return 0; }
+    }
+
+    class Ring : Circle, ISolid { }
+
+    struct Point : System.IEquatable<Point>
+    {
+        public bool Equals(Point other) => true;
+    }
+
+    class Unrelated { }
+}
+"#;
+    let sources = [("Shapes.cs", shapes)];
+    assert_eq!(
+        find(&sources, NodeType::DerivedType, "Shapes.IShape"),
+        vec!["Shapes.cs:4:14", "Shapes.cs:6:19", "Shapes.cs:12:10", "Shapes.cs:17:10"],
+    );
+    assert_eq!(
+        find(&sources, NodeType::DerivedType, "Shapes.Shape"),
+        vec!["Shapes.cs:12:10", "Shapes.cs:17:10"],
+    );
+    assert_eq!(
+        find(&sources, NodeType::DerivedType, "System.IEquatable"),
+        vec!["Shapes.cs:19:11"],
+    );
+    // members inherited from a base class are found through the derived class
+    assert_eq!(
+        find(&sources, NodeType::Method, "Shapes.Shape.Log"),
+        vec!["Shapes.cs:14:45"],
+    );
+}
+
+#[test]
+fn implementations_and_overrides() {
+    let handlers = r#"namespace Web
+{
+    interface IHandler
+    {
+        void Handle(string path);
+        bool Reusable { get; }
+    }
+
+    abstract class Base
+    {
+        public abstract void Run();
+        public virtual void Stop() { }
+    }
+
+    class Handler : IHandler
+    {
+        public void Handle(string path) { }
+        public void Handle(int code) { }
+        public bool Reusable => true;
+    }
+
+    class Explicit : IHandler
+    {
+        void IHandler.Handle(string path) { }
+        bool IHandler.Reusable => false;
+    }
+
+    class Worker : Base
+    {
+        public override void Run() { }
+    }
+
+    class Stopper : Worker
+    {
+        public override void Stop() { }
+        public new void Run() { }
+    }
+}
+"#;
+    // a page whose base list and members are split over the code-behind and designer files
+    let page = r#"namespace Web
+{
+    public partial class Default
+    {
+        public void Handle(string path) { }
+    }
+}
+"#;
+    let designer = r#"namespace Web
+{
+    public partial class Default : IHandler
+    {
+        public bool Reusable => true;
+    }
+}
+"#;
+    let sources = [("Handlers.cs", handlers), ("Default.aspx.cs", page), ("Default.aspx.designer.cs", designer)];
+    assert_eq!(
+        find(&sources, NodeType::Implementation, "Web.IHandler.Handle"),
+        vec!["Default.aspx.cs:5:20", "Handlers.cs:17:20", "Handlers.cs:24:22"],
+    );
+    assert_eq!(
+        find(&sources, NodeType::Implementation, "Web.IHandler.*"),
+        vec!["Default.aspx.cs:5:20", "Default.aspx.designer.cs:5:20", "Handlers.cs:17:20", "Handlers.cs:19:20", "Handlers.cs:24:22", "Handlers.cs:25:22"],
+    );
+    assert_eq!(
+        find(&sources, NodeType::Override, "Web.Base.Run"),
+        vec!["Handlers.cs:30:29"],
+    );
+    assert_eq!(
+        find(&sources, NodeType::Override, "Web.Base.Stop"),
+        vec!["Handlers.cs:35:29"],
+    );
+}
+
+#[test]
+fn decorated_declarations() {
+    let source = r#"using System;
+
+namespace App
+{
+    class AuditAttribute : Attribute
+    {
+        public AuditAttribute() { }
+        public AuditAttribute(string reason) { }
+    }
+
+    [Obsolete]
+    class Legacy
+    {
+        [Audit] public int Count;
+        [ObsoleteAttribute("use Run")] void Start() { }
+        [Audit("entry"), Serializable] void Run() { }
+        void Stop([Audit] int code) { }
+    }
+
+    [Serializable]
+    struct Point { }
+}
+"#;
+    let sources = [("App.cs", source)];
+    assert_eq!(
+        find(&sources, NodeType::Decorated, "System.Obsolete"),
+        vec!["App.cs:12:10", "App.cs:15:44"],
+    );
+    assert_eq!(
+        find(&sources, NodeType::Decorated, "App.AuditAttribute"),
+        vec!["App.cs:14:27", "App.cs:16:44", "App.cs:17:30"],
+    );
+    assert_eq!(
+        find(&sources, NodeType::Decorated, "System.Serial*"),
+        vec!["App.cs:16:44", "App.cs:21:11"],
+    );
+}
+
+#[test]
+fn external_declarations() {
+    let listing = r#"Newtonsoft.Json.JsonConvert
+static Newtonsoft.Json.JsonConvert.SerializeObject(object? value) -> string!
+static Newtonsoft.Json.JsonConvert.DeserializeObject(string! value, Newtonsoft.Json.JsonSerializerSettings! settings) -> object?
+Newtonsoft.Json.JsonSerializerSettings
+Newtonsoft.Json.JsonSerializerSettings.JsonSerializerSettings() -> void
+Newtonsoft.Json.IContractResolver
+Newtonsoft.Json.IContractResolver.ResolveContract(System.Type! type) -> object!
+"#;
+    let source = r#"using Newtonsoft.Json;
+
+namespace App
+{
+    class Resolver : IContractResolver
+    {
+        public object ResolveContract(System.Type type) => null;
+    }
+
+    class Program
+    {
+        void Run()
+        {
+            var json = JsonConvert.SerializeObject(new JsonSerializerSettings());
+        }
+    }
+}
+"#;
+    let workspace = Workspace::new(&[("api/PublicAPI.Shipped.txt", listing), ("src/Program.cs", source)]);
+    workspace.index_external("Newtonsoft.Json", &["api"]);
+    workspace.index(&["src"], None);
+    // the references in the sources resolve to the external declarations, and the references in
+    // the external declarations themselves are not reported
+    assert_eq!(
+        workspace.find(NodeType::Class, "Newtonsoft.Json.JsonSerializerSettings", None),
+        vec!["src/Program.cs:14:55"],
+    );
+    assert_eq!(
+        workspace.find(NodeType::Invocation, "Newtonsoft.Json.JsonConvert.SerializeObject", None),
+        vec!["src/Program.cs:14:35"],
+    );
+    assert_eq!(
+        workspace.find(NodeType::Implementation, "Newtonsoft.Json.IContractResolver.*", None),
+        vec!["src/Program.cs:7:22"],
+    );
+    assert_eq!(
+        workspace.find(NodeType::DerivedType, "Newtonsoft.Json.IContractResolver", None),
+        vec!["src/Program.cs:5:10"],
+    );
+}
+
+#[test]
+fn framework_api_surfaces() {
+    let source = r#"using System;
+using System.Configuration;
+using System.Web.UI;
+
+namespace Site
+{
+    public class Default : Page
+    {
+        void Load(string text)
+        {
+            var setting = ConfigurationManager.AppSettings;
+            ArgumentNullException.ThrowIfNull(text, "text");
+            var clamped = Math.Clamp(1, 0, 2);
+        }
+    }
+}
+"#;
+    let find = |framework: Option<&str>, node_type: NodeType, query: &str| {
+        let workspace = Workspace::new(&[("Default.aspx.cs", source)]);
+        workspace.index(&["."], framework);
+        workspace.find(node_type, query, framework)
+    };
+    // .NET Framework has System.Web and System.Configuration, but not the later additions to System
+    assert_eq!(find(Some("net48"), NodeType::DerivedType, "System.Web.UI.Page"), vec!["Default.aspx.cs:7:17"]);
+    assert_eq!(find(Some("net48"), NodeType::Property, "System.Configuration.*"), vec!["Default.aspx.cs:11:47"]);
+    assert_eq!(find(Some("net48"), NodeType::Invocation, "System.*"), Vec::<String>::new());
+    // later versions of .NET have them the other way around
+    assert_eq!(find(Some("net8.0"), NodeType::DerivedType, "System.Web.UI.Page"), Vec::<String>::new());
+    assert_eq!(find(Some("net8.0"), NodeType::Property, "System.Configuration.*"), Vec::<String>::new());
+    assert_eq!(find(Some("net8.0"), NodeType::Invocation, "System.*"), vec!["Default.aspx.cs:12:34", "Default.aspx.cs:13:31"]);
+    assert_eq!(find(Some("net5.0"), NodeType::Invocation, "System.*"), vec!["Default.aspx.cs:13:31"]);
+    // and without a framework every one of them is active
+    assert_eq!(find(None, NodeType::DerivedType, "System.Web.UI.Page"), vec!["Default.aspx.cs:7:17"]);
+    assert_eq!(find(None, NodeType::Invocation, "System.*"), vec!["Default.aspx.cs:12:34", "Default.aspx.cs:13:31"]);
+}
+
+#[test]
+fn excluded_files() {
+    let money = "namespace Shop { class Money { } }\n";
+    let uses = |name: &str| format!("namespace Shop {{ class {} {{ Money total; }} }}\n", name);
+    let (program, generated, legacy) = (uses("Program"), uses("Generated"), uses("Legacy"));
+    let workspace = Workspace::new(&[
+        ("Shop/Shop.csproj", r#"<Project Sdk="Microsoft.NET.Sdk"></Project>"#),
+        ("Shop/Money.cs", money),
+        ("Shop/Program.cs", &program),
+        ("Shop/obj/Debug/Generated.cs", &generated),
+        ("Shop/Legacy/Legacy.cs", &legacy),
+    ]);
+    workspace.index(&["."], None);
+    assert_eq!(
+        workspace.find(NodeType::TypeReference, "Shop.Money", None),
+        vec!["Shop/Legacy/Legacy.cs:1:32", "Shop/Program.cs:1:33"],
+    );
+    // a file that the project stops compiling is dropped from the index
+    std::fs::write(
+        workspace.root().join("Shop/Shop.csproj"),
+        r#"<Project Sdk="Microsoft.NET.Sdk"><ItemGroup><Compile Remove="Legacy/**" /></ItemGroup></Project>"#,
+    ).unwrap();
+    workspace.index(&["Shop/Shop.csproj"], None);
+    assert_eq!(
+        workspace.find(NodeType::TypeReference, "Shop.Money", None),
+        vec!["Shop/Program.cs:1:33"],
+    );
+}
+
+#[test]
+fn files_that_failed_to_index() {
+    let money = "namespace Shop { class Money { } }\n";
+    let program = "namespace Shop { class Program { Money total; } }\n";
+    let broken = "namespace Shop { class Broken { Money total; }\n";
+    let workspace = Workspace::new(&[("Money.cs", money), ("Program.cs", program), ("Broken.cs", broken)]);
+    workspace.index(&["."], None);
+    assert_eq!(
+        workspace.find(NodeType::Class, "Shop.Money", None),
+        vec!["Program.cs:1:33"],
+    );
+}
+
+#[test]
+fn keyword_types() {
+    let source = r#"namespace App
+{
+    class Program
+    {
+        int Run(string text, object value)
+        {
+            if (string.IsNullOrEmpty(text)) { }
+            var message = string.Format("{0}", value);
+            var count = text.Length + int.Parse("1");
+            return value.GetHashCode();
+        }
+    }
+}
+"#;
+    let sources = [("Program.cs", source)];
+    assert_eq!(
+        find(&sources, NodeType::Method, "System.String.*"),
+        vec!["Program.cs:7:23", "Program.cs:8:33"],
+    );
+    assert_eq!(
+        find(&sources, NodeType::Property, "System.String.Length"),
+        vec!["Program.cs:9:29"],
+    );
+    assert_eq!(
+        find(&sources, NodeType::Invocation, "System.Int32.Parse"),
+        vec!["Program.cs:9:42"],
+    );
+    assert_eq!(
+        find(&sources, NodeType::Method, "System.Object.GetHashCode"),
+        vec!["Program.cs:10:25"],
+    );
+}
+
+#[test]
+fn index_options() {
+    let money = "namespace Shop { class Money { } }\n";
+    let program = "namespace Shop { class Program { Money total; } }\n";
+    let report = "namespace Shop { class Report { Money total; } }\n";
+    let workspace = Workspace::new(&[("Money.cs", money), ("Program.cs", program), ("Report.cs", report)]);
+    // files that take longer than the maximum time are stored as timed out, and not queried
+    let mut index_args = workspace.index_args(&["."]);
+    index_args.max_file_time = Some(std::time::Duration::ZERO);
+    workspace.index_with(index_args, None);
+    assert_eq!(workspace.find(NodeType::Class, "Shop.Money", None), Vec::<String>::new());
+    // indexing continues from a file, skipping the ones before it
+    let mut index_args = workspace.index_args(&["."]);
+    index_args.force = true;
+    index_args.continue_from = Some(workspace.root().join("Program.cs"));
+    workspace.index_with(index_args, None);
+    assert_eq!(workspace.find(NodeType::Class, "Shop.Money", None), Vec::<String>::new());
+    let mut index_args = workspace.index_args(&["Money.cs"]);
+    index_args.force = true;
+    workspace.index_with(index_args, None);
+    assert_eq!(workspace.find(NodeType::Class, "Shop.Money", None), vec!["Program.cs:1:33", "Report.cs:1:32"]);
+}
+
+#[test]
+fn result_variables() {
+    let source = r#"namespace Shop
+{
+    class Money
+    {
+        public static Money Zero;
+        public Money Add(Money other) => other;
+    }
+
+    class Program
+    {
+        Money Run() => Money.Zero.Add(null);
+    }
+}
+"#;
+    let workspace = Workspace::new(&[("Shop.cs", source)]);
+    workspace.index(&["."], None);
+    let variables = |node_type: NodeType, query: &str| -> Vec<Vec<(String, String)>> {
+        workspace.results(node_type, query, None).into_iter()
+            .map(|result| result.variables.into_iter()
+                .map(|(name, value)| match value {
+                    Variable::String(value) => (name, value),
+                    value => panic!("variable {} is not a string: {:?}", name, value),
+                })
+                .collect())
+            .collect()
+    };
+    let variable = |name: &str, value: &str| (name.to_string(), value.to_string());
+    assert_eq!(
+        variables(NodeType::Method, "Shop.Money.Add"),
+        vec![vec![variable("fullyQualifiedName", "Shop.Money.Add"), variable("name", "Add"), variable("nodeType", "method")]],
+    );
+    assert_eq!(
+        variables(NodeType::Field, "Shop.Money.*"),
+        vec![vec![variable("fullyQualifiedName", "Shop.Money.Zero"), variable("name", "Zero"), variable("nodeType", "field")]],
+    );
+}
+
+#[test]
+fn same_named_decoys() {
+    let source = r#"using System.Configuration;
+
+namespace App
+{
+    class Local
+    {
+        public static string AppSettings;
+        public static string Get(string key) => key;
+    }
+
+    class Program
+    {
+        void Run()
+        {
+            var settings = ConfigurationManager.AppSettings;
+            var value = ConfigurationManager.AppSettings.Get("key");
+            var local = Local.AppSettings;
+            var got = Local.Get("key");
+            string AppSettings = null;
+            var copy = AppSettings;
+        }
+    }
+}
+"#;
+    let sources = [("Program.cs", source)];
+    // an exact name finds the member it names, and none of the same-named members
+    assert_eq!(
+        find(&sources, NodeType::Property, "System.Configuration.ConfigurationManager.AppSettings"),
+        vec!["Program.cs:15:48", "Program.cs:16:45"],
+    );
+    assert_eq!(
+        find(&sources, NodeType::Field, "App.Local.AppSettings"),
+        vec!["Program.cs:17:30"],
+    );
+    assert_eq!(
+        find(&sources, NodeType::Method, "System.Collections.Specialized.NameValueCollection.Get"),
+        vec!["Program.cs:16:57"],
+    );
+    assert_eq!(
+        find(&sources, NodeType::Method, "App.Local.Get"),
+        vec!["Program.cs:18:28"],
+    );
+    // and so does a namespace
+    assert_eq!(
+        find(&sources, NodeType::Namespace, "System.Configuration.*"),
+        vec!["Program.cs:15:27", "Program.cs:15:48", "Program.cs:16:24", "Program.cs:16:45"],
+    );
+}
@@ -0,0 +1,56 @@
+// Copyright 2025 shawn@hurley.page
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests of the preprocessor symbols and builtins configuration of target frameworks.
+
+use tree_sitter_stack_graphs_c_sharp::builtins_config_for_framework;
+use tree_sitter_stack_graphs_c_sharp::framework::define_constants;
+
+#[test]
+fn framework_constants() {
+    let cases: &[(&str, &[&str])] = &[
+        ("net20", &["NETFRAMEWORK", "NET20", "NET20_OR_GREATER"]),
+        ("net462", &["NETFRAMEWORK", "NET462", "NET20_OR_GREATER", "NET35_OR_GREATER", "NET40_OR_GREATER", "NET45_OR_GREATER", "NET451_OR_GREATER", "NET452_OR_GREATER", "NET46_OR_GREATER", "NET461_OR_GREATER", "NET462_OR_GREATER"]),
+        ("netcoreapp2.1", &["NETCOREAPP", "NETCOREAPP2_1", "NETCOREAPP1_0_OR_GREATER", "NETCOREAPP1_1_OR_GREATER", "NETCOREAPP2_0_OR_GREATER", "NETCOREAPP2_1_OR_GREATER"]),
+        ("netstandard1.1", &["NETSTANDARD", "NETSTANDARD1_1", "NETSTANDARD1_0_OR_GREATER", "NETSTANDARD1_1_OR_GREATER"]),
+        ("net6.0", &["NET", "NET6_0", "NET5_0_OR_GREATER", "NET6_0_OR_GREATER", "NETCOREAPP", "NETCOREAPP1_0_OR_GREATER", "NETCOREAPP1_1_OR_GREATER", "NETCOREAPP2_0_OR_GREATER", "NETCOREAPP2_1_OR_GREATER", "NETCOREAPP2_2_OR_GREATER", "NETCOREAPP3_0_OR_GREATER", "NETCOREAPP3_1_OR_GREATER"]),
+    ];
+    for (moniker, constants) in cases {
+        let constants = constants.iter().map(|constant| constant.to_string()).collect();
+        assert_eq!(define_constants(moniker), Some(constants), "target framework {}", moniker);
+    }
+}
+
+#[test]
+fn framework_moniker_spellings() {
+    // case, surrounding whitespace and an operating system suffix do not change the framework
+    for moniker in ["NET8.0", " net8.0 ", "net8.0-windows", "net8.0-windows10.0.19041.0"] {
+        assert_eq!(define_constants(moniker), define_constants("net8.0"), "target framework {}", moniker);
+    }
+}
+
+#[test]
+fn unknown_frameworks() {
+    for moniker in ["", "net", "net5", "net4.8", "net49", "netcoreapp5.0", "netstandard3.0", "mono", "net8"] {
+        assert_eq!(define_constants(moniker), None, "target framework {}", moniker);
+    }
+}
+
+#[test]
+fn framework_builtins_config() {
+    let config = builtins_config_for_framework("net5.0").unwrap();
+    assert!(config.lines().any(|line| line == "TARGET_FRAMEWORK = net5.0"), "config\n{}", config);
+    assert!(config.lines().any(|line| line.starts_with("DEFINE_CONSTANTS = ;NET;NET5_0;NET5_0_OR_GREATER;NETCOREAPP;")), "config\n{}", config);
+    assert!(builtins_config_for_framework("net49").is_err());
+}
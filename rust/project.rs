@@ -0,0 +1,188 @@
+// Copyright 2025 shawn@hurley.page
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests of the files that project files compile.
+
+use std::path::Path;
+use tree_sitter_stack_graphs_c_sharp::cli::project::Glob;
+use tree_sitter_stack_graphs_c_sharp::cli::project::Project;
+
+#[test]
+fn glob_matches() {
+    let root = Path::new("/work/App");
+    let cases: &[(&str, &str, bool)] = &[
+        ("**/*.cs", "/work/App/Program.cs", true),
+        ("**/*.cs", "/work/App/Pages/Home/Index.cs", true),
+        ("**/*.cs", "/work/App/Program.csx", false),
+        ("**/*.cs", "/work/Other/Program.cs", false),
+        ("*.cs", "/work/App/Program.cs", true),
+        ("*.cs", "/work/App/Pages/Index.cs", false),
+        ("?.cs", "/work/App/A.cs", true),
+        ("?.cs", "/work/App/AB.cs", false),
+        ("Pages/*.cs", "/work/App/pages/INDEX.CS", true),
+        ("bin/**", "/work/App/bin/Debug/net8.0/App.cs", true),
+        ("bin/**", "/work/App/Pages/bin/App.cs", false),
+        ("**/.*/**", "/work/App/.vs/App.cs", true),
+        ("**/.*/**", "/work/App/Pages/.git/objects/App.cs", true),
+        ("**/.*/**", "/work/App/Pages/App.cs", false),
+        ("./Program.cs", "/work/App/Program.cs", true),
+        ("../Shared/**/*.cs", "/work/Shared/Links/Link.cs", true),
+        ("../Shared/**/*.cs", "/work/App/Shared/Link.cs", false),
+        ("Program.cs", "/work/App/Program.cs", true),
+        ("Program.cs", "/work/App/Pages/Program.cs", false),
+        ("Pro[gram].cs", "/work/App/Pro[gram].cs", true),
+        ("Pro[gram].cs", "/work/App/Prog.cs", false),
+    ];
+    for (pattern, path, matches) in cases {
+        assert_eq!(Glob::new(root, pattern).matches(Path::new(path)), *matches, "pattern {} for {}", pattern, path);
+    }
+}
+
+#[test]
+fn glob_files() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path().canonicalize().unwrap();
+    for file in ["Program.cs", "Pages/Index.cs", "Pages/Index.cshtml", "Pages/Shared/Layout.cs"] {
+        let path = root.join(file);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, "").unwrap();
+    }
+    let cases: &[(&str, &[&str])] = &[
+        ("**/*.cs", &["Pages/Index.cs", "Pages/Shared/Layout.cs", "Program.cs"]),
+        ("Pages/**", &["Pages/Index.cs", "Pages/Shared/Layout.cs"]),
+        ("Pages/*.cs", &["Pages/Index.cs"]),
+        ("Program.cs", &["Program.cs"]),
+        ("Missing.cs", &[]),
+        ("Missing/**/*.cs", &[]),
+    ];
+    for (pattern, expected) in cases {
+        let mut files: Vec<String> = Glob::new(&root, pattern).files().unwrap().iter()
+            .map(|file| file.strip_prefix(&root).unwrap().to_string_lossy().to_string())
+            .collect();
+        files.sort();
+        assert_eq!(files, *expected, "pattern {}", pattern);
+    }
+}
+
+#[test]
+fn compile_items() {
+    let files = [
+        "App/Program.cs",
+        "App/Pages/Index.cs",
+        "App/Properties/AssemblyInfo.cs",
+        "App/Generated/Model.cs",
+        "App/bin/Debug/Old.cs",
+        "App/obj/Debug/App.AssemblyInfo.cs",
+        "App/.vs/Cache.cs",
+        "App/README.md",
+        "Shared/Link.cs",
+    ];
+    let cases: &[(&str, &str, &[&str])] = &[
+        (
+            "an SDK-style project compiles its files outside of bin, obj and hidden directories",
+            r#"<Project Sdk="Microsoft.NET.Sdk"></Project>"#,
+            &["App/Generated/Model.cs", "App/Pages/Index.cs", "App/Program.cs", "App/Properties/AssemblyInfo.cs"],
+        ),
+        (
+            "the SDK may be an element",
+            r#"<Project><Sdk Name="Microsoft.NET.Sdk" /></Project>"#,
+            &["App/Generated/Model.cs", "App/Pages/Index.cs", "App/Program.cs", "App/Properties/AssemblyInfo.cs"],
+        ),
+        (
+            "items remove files",
+            r#"<Project Sdk="Microsoft.NET.Sdk">
+  <ItemGroup>
+    <Compile Remove="Generated\**;Properties/*.cs" />
+  </ItemGroup>
+</Project>"#,
+            &["App/Pages/Index.cs", "App/Program.cs"],
+        ),
+        (
+            "and include them again in the order they appear in, also outside of the project",
+            r#"<Project Sdk="Microsoft.NET.Sdk">
+  <ItemGroup>
+    <Compile Remove="**/*.cs" />
+    <Compile Include="Pages/**/*.cs;..\Shared\Link.cs" />
+  </ItemGroup>
+</Project>"#,
+            &["App/Pages/Index.cs", "Shared/Link.cs"],
+        ),
+        (
+            "default excludes may be added to",
+            r#"<Project Sdk="Microsoft.NET.Sdk">
+  <PropertyGroup>
+    <DefaultItemExcludes>$(DefaultItemExcludes);Generated/**</DefaultItemExcludes>
+  </PropertyGroup>
+</Project>"#,
+            &["App/Pages/Index.cs", "App/Program.cs", "App/Properties/AssemblyInfo.cs"],
+        ),
+        (
+            "default items may be disabled",
+            r#"<Project Sdk="Microsoft.NET.Sdk">
+  <PropertyGroup>
+    <EnableDefaultCompileItems>False</EnableDefaultCompileItems>
+  </PropertyGroup>
+  <ItemGroup>
+    <Compile Include="Program.cs" />
+  </ItemGroup>
+</Project>"#,
+            &["App/Program.cs"],
+        ),
+        (
+            "all default items too",
+            r#"<Project Sdk="Microsoft.NET.Sdk">
+  <PropertyGroup>
+    <EnableDefaultItems>false</EnableDefaultItems>
+  </PropertyGroup>
+</Project>"#,
+            &[],
+        ),
+        (
+            "a legacy project compiles only the files it lists, and includes exclude files",
+            r#"<?xml version="1.0" encoding="utf-8"?>
+<Project ToolsVersion="15.0" xmlns="http://schemas.microsoft.com/developer/msbuild/2003">
+  <ItemGroup>
+    <Compile Include="Program.cs" />
+    <Compile Include="Properties\AssemblyInfo.cs" />
+    <Compile Include="**\*.cs" Exclude="bin\**;obj\**;.vs\**;Pages\**" />
+  </ItemGroup>
+</Project>"#,
+            &["App/Generated/Model.cs", "App/Program.cs", "App/Properties/AssemblyInfo.cs"],
+        ),
+        (
+            "comments and paths with properties are left out",
+            r#"<Project Sdk="Microsoft.NET.Sdk">
+  <!-- <Compile Remove="Program.cs" /> -->
+  <ItemGroup>
+    <Compile Remove="$(GeneratedDir)/**;Generated/**" />
+  </ItemGroup>
+</Project>"#,
+            &["App/Pages/Index.cs", "App/Program.cs", "App/Properties/AssemblyInfo.cs"],
+        ),
+    ];
+    for (description, project, expected) in cases {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().canonicalize().unwrap();
+        for file in files.iter().chain(&["App/App.csproj"]) {
+            let path = root.join(file);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(&path, if file.ends_with(".csproj") { project } else { "" }).unwrap();
+        }
+        let project = Project::load(&root.join("App/App.csproj")).unwrap();
+        let compiled: Vec<String> = project.files.iter()
+            .map(|file| file.strip_prefix(&root).unwrap().to_string_lossy().to_string())
+            .collect();
+        assert_eq!(compiled, *expected, "{}", description);
+    }
+}
@@ -0,0 +1,125 @@
+// Copyright 2025 shawn@hurley.page
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests of the conversion of public API listings into C# declarations.
+
+use tree_sitter_stack_graphs_c_sharp::cli::public_api::to_csharp;
+
+#[test]
+fn listings() {
+    let cases: &[(&str, &str)] = &[
+        // members of a class, with the `!` annotations of non-nullable types dropped
+        (
+            r#"Newtonsoft.Json.JsonConvert
+static Newtonsoft.Json.JsonConvert.SerializeObject(object? value) -> string!
+static Newtonsoft.Json.JsonConvert.DefaultSettings.get -> System.Func<Newtonsoft.Json.JsonSerializerSettings!>?
+static Newtonsoft.Json.JsonConvert.DefaultSettings.set -> void
+const Newtonsoft.Json.JsonConvert.True = "true" -> string!
+"#,
+            r#"namespace Newtonsoft.Json
+{
+    public class JsonConvert
+    {
+        public static string SerializeObject(object? value);
+        public static System.Func<Newtonsoft.Json.JsonSerializerSettings>? DefaultSettings { get; set; }
+        public const string True = "true";
+    }
+}
+"#,
+        ),
+        // a type whose members are of the type itself is an enum
+        (
+            r#"Newtonsoft.Json.Formatting
+Newtonsoft.Json.Formatting.None = 0 -> Newtonsoft.Json.Formatting
+Newtonsoft.Json.Formatting.Indented = 1 -> Newtonsoft.Json.Formatting
+"#,
+            r#"namespace Newtonsoft.Json
+{
+    public enum Formatting
+    {
+        None = 0,
+        Indented = 1,
+    }
+}
+"#,
+        ),
+        // interfaces, constructors, indexers and nested types
+        (
+            r#"Acme.IClock
+Acme.IClock.Now.get -> System.DateTime
+Acme.Index
+Acme.Index.Index(int value) -> void
+Acme.Index.this[int position].get -> string!
+Acme.Index.Entry
+Acme.Index.Entry.Entry() -> void
+Acme.Index.Entry.Key -> string!
+"#,
+            r#"namespace Acme
+{
+    public interface IClock
+    {
+        public System.DateTime Now { get; }
+    }
+    public class Index
+    {
+        public Index(int value);
+        public string this[int position] { get; }
+        public class Entry
+        {
+            public Entry();
+            public string Key;
+        }
+    }
+}
+"#,
+        ),
+        // comments, removed entries, operators and finalizers are left out; generic types and
+        // methods, default values and oblivious declarations are kept
+        (
+            r#"# a comment
+*REMOVED*Acme.Old
+Acme.Money
+~Acme.Money.Add(Acme.Money other) -> Acme.Money
+static Acme.Money.operator +(Acme.Money left, Acme.Money right) -> Acme.Money
+Acme.Money.~Money() -> void
+abstract Acme.Money.Rate(bool exact = 1 != 2) -> decimal
+Acme.Money.Amount -> decimal
+Acme.Money.Add<T>(T! value) -> void
+sealed Acme.Map<TKey, TValue>
+Acme.Map<TKey, TValue>.Map() -> void
+Acme.Map<TKey, TValue>.Get(TKey key) -> TValue
+"#,
+            r#"namespace Acme
+{
+    public sealed class Map<TKey, TValue>
+    {
+        public Map();
+        public TValue Get(TKey key);
+    }
+    public class Money
+    {
+        public Acme.Money Add(Acme.Money other);
+        public abstract decimal Rate(bool exact = 1 != 2);
+        public decimal Amount;
+        public void Add<T>(T value);
+    }
+}
+"#,
+        ),
+        ("", ""),
+    ];
+    for (listing, expected) in cases {
+        assert_eq!(to_csharp(listing), *expected, "listing\n{}", listing);
+    }
+}
@@ -0,0 +1,120 @@
+// Copyright 2025 shawn@hurley.page
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests of the query grammar of `find-node`.
+
+use tree_sitter_stack_graphs_c_sharp::cli::query::Search;
+
+#[test]
+fn split_query() {
+    let cases: &[(&str, &[&str])] = &[
+        ("System", &["System"]),
+        ("System.Configuration.ConfigurationManager", &["System", "Configuration", "ConfigurationManager"]),
+        ("System.Config*.*", &["System", "Config*", "*"]),
+        ("System.**.Page", &["System", "**", "Page"]),
+        ("System./Config(uration)?.Manager/.AppSettings", &["System", "/Config(uration)?.Manager/", "AppSettings"]),
+        ("/a\\/b/", &["/a\\/b/"]),
+    ];
+    for (query, segments) in cases {
+        assert_eq!(Search::split_query(query).unwrap(), *segments, "query {}", query);
+    }
+}
+
+#[test]
+fn split_query_errors() {
+    for query in ["", "System..String", "System.", ".System", "System./Config", "System./Config/uration"] {
+        assert!(Search::split_query(query).is_err(), "query {}", query);
+    }
+}
+
+#[test]
+fn split_signature() {
+    let cases: &[(&str, &str, Option<&str>)] = &[
+        ("App.Greeter.Greet", "App.Greeter.Greet", None),
+        ("App.Greeter.Greet()", "App.Greeter.Greet", Some("()")),
+        ("App.Greeter.Greet(string, int)", "App.Greeter.Greet", Some("(string, int)")),
+        ("App.Greeter.Greet(Func<(int, int), bool>)", "App.Greeter.Greet", Some("(Func<(int, int), bool>)")),
+        ("App.Greeter./Gre(et)?/", "App.Greeter./Gre(et)?/", None),
+        ("App.Greeter./Greet(s)/", "App.Greeter./Greet(s)/", None),
+    ];
+    for (query, name, signature) in cases {
+        assert_eq!(Search::split_signature(query).unwrap(), (*name, *signature), "query {}", query);
+    }
+    assert!(Search::split_signature("App.Greeter.Greet)").is_err());
+}
+
+#[test]
+fn create_search_signature() {
+    let search = Search::create_search("App.Greeter.Greet(System.String, int?, Dictionary<string, int>)".to_string()).unwrap();
+    assert_eq!(search.signature(), Some(&["string".to_string(), "int?".to_string(), "Dictionary<string,int>".to_string()][..]));
+    let search = Search::create_search("App.Greeter.Greet".to_string()).unwrap();
+    assert_eq!(search.signature(), None);
+}
+
+#[test]
+fn create_search_errors() {
+    for query in [
+        "App.Greeter.Greet(int",
+        "App.Greet(er.Greet",
+        "App.Greeter.*(int)",
+        "App.**(int)",
+        "App./Greet[/",
+    ] {
+        assert!(Search::create_search(query.to_string()).is_err(), "query {}", query);
+    }
+}
+
+#[test]
+fn matches_names() {
+    let cases: &[(&str, &[&str], &[&str], bool)] = &[
+        // exact names
+        ("System.String", &["System"], &["String"], true),
+        ("System.String", &["System"], &["Strings"], false),
+        ("System.String", &["System", "Text"], &["String"], false),
+        ("System.String.Join", &["System"], &["String", "Join"], true),
+        ("System.String", &["System"], &["String", "Join"], false),
+        // globs
+        ("System.Str*", &["System"], &["String"], true),
+        ("System.Str?ng", &["System"], &["String"], true),
+        ("System.Str?ng", &["System"], &["Strong", "Join"], false),
+        ("Sys*.String", &["System", "IO"], &["String"], false),
+        // regular expressions
+        ("System./Config(uration)?Manager/", &["System"], &["ConfigManager"], true),
+        ("System./Config(uration)?Manager/", &["System"], &["ConfigurationManager"], true),
+        ("System./Config(uration)?Manager/", &["System"], &["ConfigurationManagers"], false),
+        ("/System.Web/.Page", &["System", "Web"], &["Page"], false),
+        // a trailing `*` names everything declared in what the other segments name
+        ("System.*", &["System"], &["String"], true),
+        ("System.*", &["System"], &["String", "Join"], true),
+        ("System.*", &["System", "IO"], &["File"], false),
+        ("System.String.*", &["System"], &["String", "Join"], true),
+        ("System.String.*", &["System"], &["String"], false),
+        // `**` spans any number of namespace segments, including none
+        ("System.**", &["System", "Web", "UI"], &["Page", "Response"], true),
+        ("System.**", &["System"], &["String"], true),
+        ("System.**", &["Microsoft"], &["String"], false),
+        ("**.Page", &["System", "Web", "UI"], &["Page"], true),
+        ("System.**.Page", &["System"], &["Page"], true),
+        ("System.**.Page", &["System", "Web", "UI"], &["Page"], true),
+        ("System.**.UI.Page", &["System", "Web", "UI"], &["Page"], true),
+        ("System.**.Web.Page", &["System", "Web", "UI"], &["Page"], false),
+        // but no type or member names
+        ("App.**.Run", &["App"], &["Program", "Run"], false),
+        ("**.Run", &["App"], &["Program", "Run"], false),
+    ];
+    for (query, namespace, names, matches) in cases {
+        let search = Search::create_search(query.to_string()).unwrap();
+        assert_eq!(search.matches_names(namespace, names), *matches, "query {} for {:?} {:?}", query, namespace, names);
+    }
+}
@@ -0,0 +1,170 @@
+// Copyright 2025 shawn@hurley.page
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests of the formats results are written in.
+
+use std::collections::BTreeMap;
+use tree_sitter_stack_graphs_c_sharp::cli::results::write_results;
+use tree_sitter_stack_graphs_c_sharp::cli::results::Location;
+use tree_sitter_stack_graphs_c_sharp::cli::results::OutputFormat;
+use tree_sitter_stack_graphs_c_sharp::cli::results::Position;
+use tree_sitter_stack_graphs_c_sharp::cli::results::Result;
+use tree_sitter_stack_graphs_c_sharp::cli::results::Variable;
+
+/// A result with variables of every kind, and one without variables.
+fn results() -> Vec<Result> {
+    let with_variables = Result {
+        file_uri: "file:///src/App/Program.cs".to_string(),
+        line_number: 12,
+        variables: BTreeMap::from([
+            ("name".to_string(), Variable::String("AppSettings".to_string())),
+            ("static".to_string(), Variable::Bool(true)),
+            ("count".to_string(), Variable::Integer(2)),
+            ("names".to_string(), Variable::List(vec![Variable::String("a".to_string())])),
+            ("map".to_string(), Variable::Map(BTreeMap::from([("key".to_string(), Variable::Integer(1))]))),
+        ]),
+        code_location: Location {
+            start_position: Position{line: 11, character: 47},
+            end_position: Position{line: 11, character: 58},
+        },
+    };
+    let without_variables = Result {
+        file_uri: "file:///src/App/Page.cs".to_string(),
+        line_number: 3,
+        variables: BTreeMap::new(),
+        code_location: Location {
+            start_position: Position{line: 2, character: 4},
+            end_position: Position{line: 2, character: 8},
+        },
+    };
+    vec![with_variables, without_variables]
+}
+
+fn write(format: OutputFormat) -> String {
+    let mut output = vec![];
+    write_results(&results(), format, &mut output).unwrap();
+    String::from_utf8(output).unwrap()
+}
+
+#[test]
+fn text() {
+    assert_eq!(
+        write(OutputFormat::Text),
+        "file:///src/App/Program.cs\t12\t11:47-11:58\nfile:///src/App/Page.cs\t3\t2:4-2:8\n",
+    );
+}
+
+#[test]
+fn json() {
+    let expected = r#"[
+  {
+    "fileURI": "file:///src/App/Program.cs",
+    "lineNumber": 12,
+    "variables": {
+      "count": 2,
+      "map": {
+        "key": 1
+      },
+      "name": "AppSettings",
+      "names": [
+        "a"
+      ],
+      "static": true
+    },
+    "codeLocation": {
+      "startPosition": {
+        "line": 11,
+        "character": 47
+      },
+      "endPosition": {
+        "line": 11,
+        "character": 58
+      }
+    }
+  },
+  {
+    "fileURI": "file:///src/App/Page.cs",
+    "lineNumber": 3,
+    "codeLocation": {
+      "startPosition": {
+        "line": 2,
+        "character": 4
+      },
+      "endPosition": {
+        "line": 2,
+        "character": 8
+      }
+    }
+  }
+]
+"#;
+    assert_eq!(write(OutputFormat::Json), expected);
+}
+
+#[test]
+fn json_lines() {
+    let expected = concat!(
+        r#"{"fileURI":"file:///src/App/Program.cs","lineNumber":12,"variables":{"count":2,"map":{"key":1},"name":"AppSettings","names":["a"],"static":true},"codeLocation":{"startPosition":{"line":11,"character":47},"endPosition":{"line":11,"character":58}}}"#,
+        "\n",
+        r#"{"fileURI":"file:///src/App/Page.cs","lineNumber":3,"codeLocation":{"startPosition":{"line":2,"character":4},"endPosition":{"line":2,"character":8}}}"#,
+        "\n",
+    );
+    assert_eq!(write(OutputFormat::Jsonl), expected);
+}
+
+#[test]
+fn yaml() {
+    let expected = r#"- fileURI: file:///src/App/Program.cs
+  lineNumber: 12
+  variables:
+    count: 2
+    map:
+      key: 1
+    name: AppSettings
+    names:
+    - a
+    static: true
+  codeLocation:
+    startPosition:
+      line: 11
+      character: 47
+    endPosition:
+      line: 11
+      character: 58
+- fileURI: file:///src/App/Page.cs
+  lineNumber: 3
+  codeLocation:
+    startPosition:
+      line: 2
+      character: 4
+    endPosition:
+      line: 2
+      character: 8
+"#;
+    assert_eq!(write(OutputFormat::Yaml), expected);
+}
+
+#[test]
+fn no_results() {
+    assert_eq!(write_empty(OutputFormat::Text), "");
+    assert_eq!(write_empty(OutputFormat::Json), "[]\n");
+    assert_eq!(write_empty(OutputFormat::Jsonl), "");
+    assert_eq!(write_empty(OutputFormat::Yaml), "[]\n");
+}
+
+fn write_empty(format: OutputFormat) -> String {
+    let mut output = vec![];
+    write_results(&[], format, &mut output).unwrap();
+    String::from_utf8(output).unwrap()
+}
@@ -0,0 +1,115 @@
+// Copyright 2025 shawn@hurley.page
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests of the parsing of query signatures and parameter lists, and of which overloads the
+//! arguments of an invocation can call.
+
+use tree_sitter_stack_graphs_c_sharp::cli::signature::parse_signature;
+use tree_sitter_stack_graphs_c_sharp::cli::signature::Argument;
+use tree_sitter_stack_graphs_c_sharp::cli::signature::Arguments;
+use tree_sitter_stack_graphs_c_sharp::cli::signature::Parameters;
+
+fn types(types: &[&str]) -> Vec<String> {
+    types.iter().map(|type_name| type_name.to_string()).collect()
+}
+
+#[test]
+fn signatures() {
+    let cases: &[(&str, &[&str])] = &[
+        ("()", &[]),
+        ("(string, int)", &["string", "int"]),
+        ("(System.String, global::System.Int32)", &["string", "int"]),
+        ("(Dictionary<string, List<System.Int32>>, int[])", &["Dictionary<string,List<int>>", "int[]"]),
+        ("(System.Func<System.Object, bool>)", &["Func<object,bool>"]),
+        ("((int, string), int?)", &["(int,string)", "int?"]),
+    ];
+    for (signature, expected) in cases {
+        assert_eq!(parse_signature(signature), types(expected), "signature {}", signature);
+    }
+}
+
+#[test]
+fn parameter_lists() {
+    let cases: &[(&str, &[&str])] = &[
+        ("()", &[]),
+        ("(string key, int count = 0)", &["string", "int"]),
+        ("([NotNull] object value, params string[] names)", &["object", "string[]"]),
+        ("(this IEnumerable<TSource> source, Func<TSource, bool> predicate)", &["IEnumerable<TSource>", "Func<TSource,bool>"]),
+        ("(ref int x, out System.String y, in long z)", &["int", "string", "long"]),
+        ("(Dictionary<string, int> map, bool equal = 1 == 2)", &["Dictionary<string,int>", "bool"]),
+        ("(string text = \"a, b\", char separator = ',')", &["string", "char"]),
+    ];
+    for (list, expected) in cases {
+        assert!(Parameters::parse(list).matches(&types(expected)), "parameter list {}", list);
+    }
+    // a type without `?` in a signature matches its nullable type as well, but not the other way
+    assert!(Parameters::parse("(object? value)").matches(&types(&["object"])));
+    assert!(Parameters::parse("(object? value)").matches(&types(&["object?"])));
+    assert!(!Parameters::parse("(object value)").matches(&types(&["object?"])));
+    assert!(!Parameters::parse("(string key, int count)").matches(&types(&["string"])));
+}
+
+#[test]
+fn same_parameter_types() {
+    let method = Parameters::parse("(System.String name, int count)");
+    assert!(method.same_types(&Parameters::parse("(string other, Int32 number = 1)")));
+    assert!(!method.same_types(&Parameters::parse("(string name)")));
+    assert!(!method.same_types(&Parameters::parse("(string name, long count)")));
+}
+
+#[test]
+fn accepted_arguments() {
+    use Argument::*;
+    let typed = |type_name: &str| Typed(type_name.to_string());
+    let number = |literal: &str| Number(literal.to_string());
+    let cases: Vec<(&str, Vec<Argument>, bool)> = vec![
+        // the number of arguments must fit
+        ("(string text)", vec![], false),
+        ("(string text)", vec![Unknown], true),
+        ("(string text)", vec![Unknown, Unknown], false),
+        ("(string text, int count = 0)", vec![Unknown], true),
+        ("(int count, params string[] names)", vec![number("1")], true),
+        ("(int count, params string[] names)", vec![number("1"), typed("string"), typed("string")], true),
+        ("(int count, params string[] names)", vec![number("1"), number("2")], false),
+        // and so must the types that are known
+        ("(string text)", vec![typed("string")], true),
+        ("(string text)", vec![typed("System.String")], true),
+        ("(string text)", vec![number("1")], false),
+        ("(int value)", vec![typed("char")], true),
+        ("(int value)", vec![typed("bool")], false),
+        ("(uint value)", vec![number("1")], true),
+        ("(long value)", vec![number("1")], true),
+        ("(long value)", vec![number("2UL")], false),
+        ("(long value)", vec![number("1.5")], false),
+        ("(float value)", vec![number("0x1F")], true),
+        ("(float value)", vec![number("1e3")], false),
+        ("(double value)", vec![number("1.5f")], true),
+        ("(double value)", vec![number("1m")], false),
+        ("(decimal value)", vec![number("1_000m")], true),
+        ("(object value)", vec![typed("Customer")], true),
+        ("(int? value)", vec![number("1")], true),
+        // other types may have conversions that cannot be told from here
+        ("(Customer value)", vec![typed("string")], true),
+        // arguments passed by name may be in any order
+        ("(string text, int count = 0)", vec![Named, number("1")], true),
+        ("(string text, int count = 0)", vec![Named, Named, Named], false),
+    ];
+    for (list, arguments, accepts) in cases {
+        assert_eq!(
+            Parameters::parse(list).accepts(&Arguments::new(&arguments)),
+            accepts,
+            "parameter list {} with arguments {:?}", list, arguments,
+        );
+    }
+}
@@ -0,0 +1,1264 @@
+// The public surface of the parts of the .NET class library that references most often resolve
+// to. Only declarations matter here: members have no bodies, and only the names, parameter lists,
+// base types and declared types that name resolution and queries look at are kept.
+//
+// Declarations that only some target frameworks have are in `#if` blocks on the symbols that the
+// .NET SDK defines for them, such as `NETFRAMEWORK` or `NET6_0_OR_GREATER`. Building the builtins
+// for a target framework leaves the other frameworks' declarations out.
+
+using System.Collections;
+using System.Collections.Generic;
+using System.Collections.Specialized;
+using System.IO;
+using System.Web.Caching;
+using System.Web.SessionState;
+
+namespace System
+{
+    public class Object
+    {
+        public Object();
+        public virtual bool Equals(object obj);
+        public virtual int GetHashCode();
+        public Type GetType();
+        public virtual string ToString();
+        public static bool ReferenceEquals(object objA, object objB);
+    }
+
+    public sealed class String : IComparable<string>, IEquatable<string>, IEnumerable<char>
+    {
+        public static readonly string Empty;
+        public String(char[] value);
+        public int Length { get; }
+        public bool Contains(string value);
+#if NETCOREAPP
+        public bool Contains(char value);
+        public bool Contains(string value, StringComparison comparisonType);
+#endif
+        public bool EndsWith(string value);
+        public bool Equals(string value, StringComparison comparisonType);
+        public int IndexOf(string value);
+        public string Replace(string oldValue, string newValue);
+        public string[] Split(params char[] separator);
+        public bool StartsWith(string value);
+        public string Substring(int startIndex);
+        public string Substring(int startIndex, int length);
+        public string ToLower();
+        public string ToUpper();
+        public string Trim();
+        public static int Compare(string strA, string strB);
+        public static string Concat(params string[] values);
+        public static bool Equals(string a, string b);
+        public static string Format(string format, params object[] args);
+        public static bool IsNullOrEmpty(string value);
+        public static bool IsNullOrWhiteSpace(string value);
+        public static string Join(string separator, params string[] value);
+    }
+
+    public struct Boolean : IComparable<bool>, IEquatable<bool>
+    {
+        public static bool Parse(string value);
+        public static bool TryParse(string value, out bool result);
+    }
+
+    public struct Char : IComparable<char>, IEquatable<char>
+    {
+        public static bool IsDigit(char c);
+        public static bool IsLetter(char c);
+        public static bool IsWhiteSpace(char c);
+    }
+
+    public struct Int32 : IComparable<int>, IEquatable<int>
+    {
+        public const int MaxValue = 2147483647;
+        public const int MinValue = -2147483648;
+        public static int Parse(string s);
+        public static bool TryParse(string s, out int result);
+    }
+
+    public struct Int64 : IComparable<long>, IEquatable<long>
+    {
+        public const long MaxValue = 9223372036854775807;
+        public static long Parse(string s);
+        public static bool TryParse(string s, out long result);
+    }
+
+    public struct Double : IComparable<double>, IEquatable<double>
+    {
+        public static double Parse(string s);
+        public static bool TryParse(string s, out double result);
+    }
+
+    public struct Decimal : IComparable<decimal>, IEquatable<decimal>
+    {
+        public static decimal Parse(string s);
+        public static bool TryParse(string s, out decimal result);
+    }
+
+    public struct Guid : IComparable<Guid>, IEquatable<Guid>
+    {
+        public static readonly Guid Empty;
+        public static Guid NewGuid();
+        public static Guid Parse(string input);
+    }
+
+    public struct DateTime : IComparable<DateTime>, IEquatable<DateTime>
+    {
+        public DateTime(int year, int month, int day);
+        public static DateTime Now { get; }
+        public static DateTime Today { get; }
+        public static DateTime UtcNow { get; }
+        public DateTime Date { get; }
+        public int Year { get; }
+        public DateTime AddDays(double value);
+        public string ToString(string format);
+        public static DateTime Parse(string s);
+    }
+
+    public struct TimeSpan : IComparable<TimeSpan>, IEquatable<TimeSpan>
+    {
+        public TimeSpan(int hours, int minutes, int seconds);
+        public double TotalMilliseconds { get; }
+        public double TotalSeconds { get; }
+        public static TimeSpan FromMinutes(double value);
+        public static TimeSpan FromSeconds(double value);
+    }
+
+    public struct Nullable<T> where T : struct
+    {
+        public bool HasValue { get; }
+        public T Value { get; }
+        public T GetValueOrDefault();
+    }
+
+    public abstract class Type
+    {
+        public string FullName { get; }
+        public string Name { get; }
+        public string Namespace { get; }
+        public static Type GetType(string typeName);
+    }
+
+    public abstract class Enum
+    {
+        public static string GetName(Type enumType, object value);
+        public static object Parse(Type enumType, string value);
+    }
+
+    public abstract class Delegate
+    {
+        public object Target { get; }
+    }
+
+    public interface IDisposable
+    {
+        void Dispose();
+    }
+
+    public interface IComparable<in T>
+    {
+        int CompareTo(T other);
+    }
+
+    public interface IEquatable<T>
+    {
+        bool Equals(T other);
+    }
+
+    public interface IServiceProvider
+    {
+        object GetService(Type serviceType);
+    }
+
+    public class EventArgs
+    {
+        public static readonly EventArgs Empty;
+        public EventArgs();
+    }
+
+    public class Exception
+    {
+        public Exception();
+        public Exception(string message);
+        public Exception(string message, Exception innerException);
+        public Exception InnerException { get; }
+        public virtual string Message { get; }
+        public virtual string StackTrace { get; }
+    }
+
+    public class SystemException : Exception
+    {
+        public SystemException();
+        public SystemException(string message);
+    }
+
+    public class ArgumentException : SystemException
+    {
+        public ArgumentException();
+        public ArgumentException(string message);
+        public ArgumentException(string message, string paramName);
+        public virtual string ParamName { get; }
+    }
+
+    public class ArgumentNullException : ArgumentException
+    {
+        public ArgumentNullException();
+        public ArgumentNullException(string paramName);
+        public ArgumentNullException(string paramName, string message);
+#if NET6_0_OR_GREATER
+        public static void ThrowIfNull(object argument, string paramName);
+#endif
+    }
+
+    public class ArgumentOutOfRangeException : ArgumentException
+    {
+        public ArgumentOutOfRangeException();
+        public ArgumentOutOfRangeException(string paramName);
+    }
+
+    public class InvalidOperationException : SystemException
+    {
+        public InvalidOperationException();
+        public InvalidOperationException(string message);
+    }
+
+    public class NotImplementedException : SystemException
+    {
+        public NotImplementedException();
+        public NotImplementedException(string message);
+    }
+
+    public class NotSupportedException : SystemException
+    {
+        public NotSupportedException();
+        public NotSupportedException(string message);
+    }
+
+    public class NullReferenceException : SystemException
+    {
+        public NullReferenceException();
+    }
+
+    public class FormatException : SystemException
+    {
+        public FormatException();
+        public FormatException(string message);
+    }
+
+    public abstract class Attribute
+    {
+        protected Attribute();
+    }
+
+    [AttributeUsage(AttributeTargets.All)]
+    public sealed class AttributeUsageAttribute : Attribute
+    {
+        public AttributeUsageAttribute(AttributeTargets validOn);
+        public bool AllowMultiple { get; set; }
+        public bool Inherited { get; set; }
+    }
+
+    public enum AttributeTargets
+    {
+        Assembly = 1,
+        Module = 2,
+        Class = 4,
+        Struct = 8,
+        Enum = 16,
+        Constructor = 32,
+        Method = 64,
+        Property = 128,
+        Field = 256,
+        Event = 512,
+        Interface = 1024,
+        Parameter = 2048,
+        Delegate = 4096,
+        ReturnValue = 8192,
+        All = 32767,
+    }
+
+    public sealed class ObsoleteAttribute : Attribute
+    {
+        public ObsoleteAttribute();
+        public ObsoleteAttribute(string message);
+        public ObsoleteAttribute(string message, bool error);
+        public bool IsError { get; }
+        public string Message { get; }
+    }
+
+    public sealed class SerializableAttribute : Attribute
+    {
+        public SerializableAttribute();
+    }
+
+    public sealed class FlagsAttribute : Attribute
+    {
+        public FlagsAttribute();
+    }
+
+    public enum StringComparison
+    {
+        CurrentCulture,
+        CurrentCultureIgnoreCase,
+        InvariantCulture,
+        InvariantCultureIgnoreCase,
+        Ordinal,
+        OrdinalIgnoreCase,
+    }
+
+    public static class Console
+    {
+        public static TextWriter Error { get; }
+        public static TextWriter Out { get; }
+        public static string ReadLine();
+        public static void Write(string value);
+        public static void Write(string format, params object[] arg);
+        public static void WriteLine();
+        public static void WriteLine(string value);
+        public static void WriteLine(object value);
+        public static void WriteLine(string format, params object[] arg);
+    }
+
+    public static class Convert
+    {
+        public static bool ToBoolean(object value);
+        public static DateTime ToDateTime(object value);
+        public static int ToInt32(object value);
+        public static int ToInt32(string value);
+        public static long ToInt64(object value);
+        public static string ToString(object value);
+        public static string ToBase64String(byte[] inArray);
+        public static byte[] FromBase64String(string s);
+    }
+
+    public static class Environment
+    {
+        public static string CurrentDirectory { get; set; }
+        public static string MachineName { get; }
+        public static string NewLine { get; }
+        public static void Exit(int exitCode);
+        public static string GetEnvironmentVariable(string variable);
+        public static string GetFolderPath(SpecialFolder folder);
+
+        public enum SpecialFolder
+        {
+            ApplicationData,
+            CommonApplicationData,
+            LocalApplicationData,
+            MyDocuments,
+            ProgramFiles,
+        }
+    }
+
+    public static class Math
+    {
+        public const double PI = 3.14159265358979;
+        public static int Abs(int value);
+        public static double Ceiling(double a);
+#if NETCOREAPP
+        public static int Clamp(int value, int min, int max);
+#endif
+        public static double Floor(double d);
+        public static int Max(int val1, int val2);
+        public static int Min(int val1, int val2);
+        public static double Round(double a);
+    }
+
+    public static class GC
+    {
+        public static void Collect();
+        public static void SuppressFinalize(object obj);
+    }
+
+    public class Uri
+    {
+        public Uri(string uriString);
+        public string AbsolutePath { get; }
+        public string AbsoluteUri { get; }
+        public string Host { get; }
+        public string Query { get; }
+    }
+
+    public class Lazy<T>
+    {
+        public Lazy();
+        public bool IsValueCreated { get; }
+        public T Value { get; }
+    }
+
+    public class Random
+    {
+        public Random();
+        public Random(int seed);
+        public virtual int Next();
+        public virtual int Next(int maxValue);
+        public virtual int Next(int minValue, int maxValue);
+    }
+}
+
+namespace System.Collections
+{
+    public interface IEnumerable
+    {
+        IEnumerator GetEnumerator();
+    }
+
+    public interface IEnumerator
+    {
+        object Current { get; }
+        bool MoveNext();
+        void Reset();
+    }
+
+    public interface ICollection : IEnumerable
+    {
+        int Count { get; }
+    }
+
+    public interface IList : ICollection
+    {
+        int Add(object value);
+        void Clear();
+        bool Contains(object value);
+        void Remove(object value);
+    }
+
+    public interface IDictionary : ICollection
+    {
+        void Add(object key, object value);
+        bool Contains(object key);
+        void Remove(object key);
+    }
+
+    public class ArrayList : IList
+    {
+        public ArrayList();
+        public virtual int Count { get; }
+        public virtual int Add(object value);
+        public virtual void Clear();
+        public virtual bool Contains(object value);
+        public virtual void Remove(object value);
+    }
+
+    public class Hashtable : IDictionary
+    {
+        public Hashtable();
+        public virtual int Count { get; }
+        public virtual ICollection Keys { get; }
+        public virtual ICollection Values { get; }
+        public virtual void Add(object key, object value);
+        public virtual bool Contains(object key);
+        public virtual bool ContainsKey(object key);
+        public virtual void Remove(object key);
+    }
+}
+
+namespace System.Collections.Generic
+{
+    public interface IEnumerable<out T> : IEnumerable
+    {
+        IEnumerator<T> GetEnumerator();
+    }
+
+    public interface IEnumerator<out T> : IDisposable, IEnumerator
+    {
+        T Current { get; }
+    }
+
+    public interface ICollection<T> : IEnumerable<T>
+    {
+        int Count { get; }
+        bool IsReadOnly { get; }
+        void Add(T item);
+        void Clear();
+        bool Contains(T item);
+        void CopyTo(T[] array, int arrayIndex);
+        bool Remove(T item);
+    }
+
+    public interface IList<T> : ICollection<T>
+    {
+        int IndexOf(T item);
+        void Insert(int index, T item);
+        void RemoveAt(int index);
+    }
+
+    public interface IReadOnlyCollection<out T> : IEnumerable<T>
+    {
+        int Count { get; }
+    }
+
+    public interface IReadOnlyList<out T> : IReadOnlyCollection<T>
+    {
+    }
+
+    public interface IDictionary<TKey, TValue> : ICollection<KeyValuePair<TKey, TValue>>
+    {
+        ICollection<TKey> Keys { get; }
+        ICollection<TValue> Values { get; }
+        void Add(TKey key, TValue value);
+        bool ContainsKey(TKey key);
+        bool Remove(TKey key);
+        bool TryGetValue(TKey key, out TValue value);
+    }
+
+    public interface IReadOnlyDictionary<TKey, TValue> : IReadOnlyCollection<KeyValuePair<TKey, TValue>>
+    {
+        IEnumerable<TKey> Keys { get; }
+        IEnumerable<TValue> Values { get; }
+        bool ContainsKey(TKey key);
+        bool TryGetValue(TKey key, out TValue value);
+    }
+
+    public interface ISet<T> : ICollection<T>
+    {
+        void UnionWith(IEnumerable<T> other);
+    }
+
+    public interface IComparer<in T>
+    {
+        int Compare(T x, T y);
+    }
+
+    public interface IEqualityComparer<in T>
+    {
+        bool Equals(T x, T y);
+        int GetHashCode(T obj);
+    }
+
+    public struct KeyValuePair<TKey, TValue>
+    {
+        public KeyValuePair(TKey key, TValue value);
+        public TKey Key { get; }
+        public TValue Value { get; }
+    }
+
+    public class List<T> : IList<T>, IReadOnlyList<T>, IList
+    {
+        public List();
+        public List(int capacity);
+        public List(IEnumerable<T> collection);
+        public int Capacity { get; set; }
+        public int Count { get; }
+        public void Add(T item);
+        public void AddRange(IEnumerable<T> collection);
+        public void Clear();
+        public bool Contains(T item);
+        public T Find(Predicate<T> match);
+        public List<T> FindAll(Predicate<T> match);
+        public void ForEach(Action<T> action);
+        public int IndexOf(T item);
+        public void Insert(int index, T item);
+        public bool Remove(T item);
+        public void RemoveAt(int index);
+        public void Reverse();
+        public void Sort();
+        public T[] ToArray();
+    }
+
+    public class Dictionary<TKey, TValue> : IDictionary<TKey, TValue>, IReadOnlyDictionary<TKey, TValue>, IDictionary
+    {
+        public Dictionary();
+        public Dictionary(int capacity);
+        public Dictionary(IEqualityComparer<TKey> comparer);
+        public int Count { get; }
+        public ICollection<TKey> Keys { get; }
+        public ICollection<TValue> Values { get; }
+        public void Add(TKey key, TValue value);
+        public void Clear();
+        public bool ContainsKey(TKey key);
+        public bool ContainsValue(TValue value);
+        public bool Remove(TKey key);
+        public bool TryGetValue(TKey key, out TValue value);
+    }
+
+    public class HashSet<T> : ISet<T>, IReadOnlyCollection<T>
+    {
+        public HashSet();
+        public HashSet(IEnumerable<T> collection);
+        public int Count { get; }
+        public bool Add(T item);
+        public void Clear();
+        public bool Contains(T item);
+        public bool Remove(T item);
+        public void UnionWith(IEnumerable<T> other);
+    }
+
+    public class Queue<T> : IEnumerable<T>, IReadOnlyCollection<T>
+    {
+        public Queue();
+        public int Count { get; }
+        public T Dequeue();
+        public void Enqueue(T item);
+        public T Peek();
+    }
+
+    public class Stack<T> : IEnumerable<T>, IReadOnlyCollection<T>
+    {
+        public Stack();
+        public int Count { get; }
+        public T Peek();
+        public T Pop();
+        public void Push(T item);
+    }
+
+    public class KeyNotFoundException : SystemException
+    {
+        public KeyNotFoundException();
+        public KeyNotFoundException(string message);
+    }
+}
+
+namespace System.Collections.Specialized
+{
+    public class NameValueCollection : ICollection
+    {
+        public NameValueCollection();
+        public string[] AllKeys { get; }
+        public int Count { get; }
+        public void Add(string name, string value);
+        public string Get(string name);
+        public string[] GetValues(string name);
+        public void Remove(string name);
+        public void Set(string name, string value);
+    }
+}
+
+namespace System.Linq
+{
+    public interface IGrouping<out TKey, out TElement> : IEnumerable<TElement>
+    {
+        TKey Key { get; }
+    }
+
+    public interface IOrderedEnumerable<out TElement> : IEnumerable<TElement>
+    {
+    }
+
+    public interface IQueryable : IEnumerable
+    {
+        Type ElementType { get; }
+    }
+
+    public interface IQueryable<out T> : IEnumerable<T>, IQueryable
+    {
+    }
+
+    public static class Enumerable
+    {
+        public static bool All<TSource>(this IEnumerable<TSource> source, Func<TSource, bool> predicate);
+        public static bool Any<TSource>(this IEnumerable<TSource> source);
+        public static bool Any<TSource>(this IEnumerable<TSource> source, Func<TSource, bool> predicate);
+        public static IEnumerable<TResult> Cast<TResult>(this IEnumerable source);
+        public static IEnumerable<TSource> Concat<TSource>(this IEnumerable<TSource> first, IEnumerable<TSource> second);
+        public static bool Contains<TSource>(this IEnumerable<TSource> source, TSource value);
+        public static int Count<TSource>(this IEnumerable<TSource> source);
+        public static IEnumerable<TSource> Distinct<TSource>(this IEnumerable<TSource> source);
+        public static IEnumerable<TResult> Empty<TResult>();
+        public static TSource First<TSource>(this IEnumerable<TSource> source);
+        public static TSource First<TSource>(this IEnumerable<TSource> source, Func<TSource, bool> predicate);
+        public static TSource FirstOrDefault<TSource>(this IEnumerable<TSource> source);
+        public static TSource FirstOrDefault<TSource>(this IEnumerable<TSource> source, Func<TSource, bool> predicate);
+        public static IEnumerable<IGrouping<TKey, TSource>> GroupBy<TSource, TKey>(this IEnumerable<TSource> source, Func<TSource, TKey> keySelector);
+        public static TSource Last<TSource>(this IEnumerable<TSource> source);
+        public static TSource Max<TSource>(this IEnumerable<TSource> source);
+        public static TSource Min<TSource>(this IEnumerable<TSource> source);
+        public static IEnumerable<TResult> OfType<TResult>(this IEnumerable source);
+        public static IOrderedEnumerable<TSource> OrderBy<TSource, TKey>(this IEnumerable<TSource> source, Func<TSource, TKey> keySelector);
+        public static IOrderedEnumerable<TSource> OrderByDescending<TSource, TKey>(this IEnumerable<TSource> source, Func<TSource, TKey> keySelector);
+        public static IEnumerable<int> Range(int start, int count);
+        public static IEnumerable<TResult> Select<TSource, TResult>(this IEnumerable<TSource> source, Func<TSource, TResult> selector);
+        public static IEnumerable<TResult> SelectMany<TSource, TResult>(this IEnumerable<TSource> source, Func<TSource, IEnumerable<TResult>> selector);
+        public static TSource Single<TSource>(this IEnumerable<TSource> source);
+        public static TSource SingleOrDefault<TSource>(this IEnumerable<TSource> source);
+        public static IEnumerable<TSource> Skip<TSource>(this IEnumerable<TSource> source, int count);
+        public static int Sum(this IEnumerable<int> source);
+        public static IEnumerable<TSource> Take<TSource>(this IEnumerable<TSource> source, int count);
+        public static IOrderedEnumerable<TSource> ThenBy<TSource, TKey>(this IOrderedEnumerable<TSource> source, Func<TSource, TKey> keySelector);
+        public static TSource[] ToArray<TSource>(this IEnumerable<TSource> source);
+        public static Dictionary<TKey, TSource> ToDictionary<TSource, TKey>(this IEnumerable<TSource> source, Func<TSource, TKey> keySelector);
+        public static List<TSource> ToList<TSource>(this IEnumerable<TSource> source);
+        public static IEnumerable<TSource> Where<TSource>(this IEnumerable<TSource> source, Func<TSource, bool> predicate);
+    }
+
+    public static class Queryable
+    {
+        public static IQueryable<TElement> AsQueryable<TElement>(this IEnumerable<TElement> source);
+    }
+}
+
+namespace System.IO
+{
+    public enum FileMode
+    {
+        CreateNew = 1,
+        Create = 2,
+        Open = 3,
+        OpenOrCreate = 4,
+        Truncate = 5,
+        Append = 6,
+    }
+
+    public enum FileAccess
+    {
+        Read = 1,
+        Write = 2,
+        ReadWrite = 3,
+    }
+
+    public enum SearchOption
+    {
+        TopDirectoryOnly,
+        AllDirectories,
+    }
+
+    public abstract class Stream : IDisposable
+    {
+        public abstract bool CanRead { get; }
+        public abstract bool CanWrite { get; }
+        public abstract long Length { get; }
+        public abstract long Position { get; set; }
+        public void CopyTo(Stream destination);
+        public void Dispose();
+        public abstract void Flush();
+        public abstract int Read(byte[] buffer, int offset, int count);
+        public abstract void Write(byte[] buffer, int offset, int count);
+    }
+
+    public class FileStream : Stream
+    {
+        public FileStream(string path, FileMode mode);
+        public FileStream(string path, FileMode mode, FileAccess access);
+        public string Name { get; }
+    }
+
+    public class MemoryStream : Stream
+    {
+        public MemoryStream();
+        public MemoryStream(byte[] buffer);
+        public virtual byte[] ToArray();
+    }
+
+    public abstract class TextReader : IDisposable
+    {
+        public void Dispose();
+        public virtual string ReadLine();
+        public virtual string ReadToEnd();
+    }
+
+    public abstract class TextWriter : IDisposable
+    {
+        public void Dispose();
+        public virtual void Flush();
+        public virtual void Write(string value);
+        public virtual void WriteLine(string value);
+    }
+
+    public class StreamReader : TextReader
+    {
+        public StreamReader(Stream stream);
+        public StreamReader(string path);
+        public virtual Stream BaseStream { get; }
+        public bool EndOfStream { get; }
+    }
+
+    public class StreamWriter : TextWriter
+    {
+        public StreamWriter(Stream stream);
+        public StreamWriter(string path);
+        public StreamWriter(string path, bool append);
+        public virtual bool AutoFlush { get; set; }
+    }
+
+    public class StringReader : TextReader
+    {
+        public StringReader(string s);
+    }
+
+    public class StringWriter : TextWriter
+    {
+        public StringWriter();
+    }
+
+    public static class File
+    {
+        public static void AppendAllText(string path, string contents);
+        public static void Copy(string sourceFileName, string destFileName);
+        public static FileStream Create(string path);
+        public static void Delete(string path);
+        public static bool Exists(string path);
+        public static void Move(string sourceFileName, string destFileName);
+        public static FileStream Open(string path, FileMode mode);
+        public static FileStream OpenRead(string path);
+        public static StreamReader OpenText(string path);
+        public static byte[] ReadAllBytes(string path);
+        public static string[] ReadAllLines(string path);
+        public static string ReadAllText(string path);
+        public static void WriteAllBytes(string path, byte[] bytes);
+        public static void WriteAllLines(string path, string[] contents);
+        public static void WriteAllText(string path, string contents);
+    }
+
+    public static class Directory
+    {
+        public static DirectoryInfo CreateDirectory(string path);
+        public static void Delete(string path, bool recursive);
+        public static bool Exists(string path);
+        public static string GetCurrentDirectory();
+        public static string[] GetDirectories(string path);
+        public static string[] GetFiles(string path);
+        public static string[] GetFiles(string path, string searchPattern);
+        public static string[] GetFiles(string path, string searchPattern, SearchOption searchOption);
+    }
+
+    public static class Path
+    {
+        public static readonly char DirectorySeparatorChar;
+        public static string ChangeExtension(string path, string extension);
+        public static string Combine(string path1, string path2);
+        public static string Combine(params string[] paths);
+        public static string GetDirectoryName(string path);
+        public static string GetExtension(string path);
+        public static string GetFileName(string path);
+        public static string GetFileNameWithoutExtension(string path);
+        public static string GetFullPath(string path);
+        public static string GetTempPath();
+    }
+
+    public abstract class FileSystemInfo
+    {
+        public virtual bool Exists { get; }
+        public string Extension { get; }
+        public virtual string FullName { get; }
+        public abstract string Name { get; }
+        public abstract void Delete();
+    }
+
+    public sealed class FileInfo : FileSystemInfo
+    {
+        public FileInfo(string fileName);
+        public DirectoryInfo Directory { get; }
+        public long Length { get; }
+        public FileStream OpenRead();
+    }
+
+    public sealed class DirectoryInfo : FileSystemInfo
+    {
+        public DirectoryInfo(string path);
+        public DirectoryInfo Parent { get; }
+        public FileInfo[] GetFiles();
+        public DirectoryInfo[] GetDirectories();
+    }
+
+    public class IOException : SystemException
+    {
+        public IOException();
+        public IOException(string message);
+    }
+
+    public class FileNotFoundException : IOException
+    {
+        public FileNotFoundException();
+        public FileNotFoundException(string message);
+        public string FileName { get; }
+    }
+
+    public class DirectoryNotFoundException : IOException
+    {
+        public DirectoryNotFoundException();
+        public DirectoryNotFoundException(string message);
+    }
+}
+
+#if NETFRAMEWORK
+namespace System.Configuration
+{
+    public static class ConfigurationManager
+    {
+        public static NameValueCollection AppSettings { get; }
+        public static ConnectionStringSettingsCollection ConnectionStrings { get; }
+        public static object GetSection(string sectionName);
+        public static Configuration OpenExeConfiguration(ConfigurationUserLevel userLevel);
+        public static Configuration OpenExeConfiguration(string exePath);
+        public static void RefreshSection(string sectionName);
+    }
+
+    public sealed class Configuration
+    {
+        public AppSettingsSection AppSettings { get; }
+        public ConnectionStringsSection ConnectionStrings { get; }
+        public string FilePath { get; }
+        public ConfigurationSection GetSection(string sectionName);
+        public void Save();
+        public void Save(ConfigurationSaveMode saveMode);
+    }
+
+    public enum ConfigurationUserLevel
+    {
+        None = 0,
+        PerUserRoaming = 10,
+        PerUserRoamingAndLocal = 20,
+    }
+
+    public enum ConfigurationSaveMode
+    {
+        Modified,
+        Minimal,
+        Full,
+    }
+
+    public abstract class ConfigurationElement
+    {
+        protected ConfigurationElement();
+        protected object this[string propertyName] { get; set; }
+        public virtual bool IsReadOnly();
+    }
+
+    public abstract class ConfigurationSection : ConfigurationElement
+    {
+        protected ConfigurationSection();
+    }
+
+    public abstract class ConfigurationElementCollection : ConfigurationElement, ICollection
+    {
+        public int Count { get; }
+        protected abstract ConfigurationElement CreateNewElement();
+        protected abstract object GetElementKey(ConfigurationElement element);
+    }
+
+    public sealed class AppSettingsSection : ConfigurationSection
+    {
+        public KeyValueConfigurationCollection Settings { get; }
+    }
+
+    public class KeyValueConfigurationCollection : ConfigurationElementCollection
+    {
+        public string[] AllKeys { get; }
+        public void Add(string key, string value);
+        public void Remove(string key);
+    }
+
+    public sealed class ConnectionStringsSection : ConfigurationSection
+    {
+        public ConnectionStringSettingsCollection ConnectionStrings { get; }
+    }
+
+    public sealed class ConnectionStringSettings : ConfigurationElement
+    {
+        public ConnectionStringSettings(string name, string connectionString);
+        public string ConnectionString { get; set; }
+        public string Name { get; set; }
+        public string ProviderName { get; set; }
+    }
+
+    public sealed class ConnectionStringSettingsCollection : ConfigurationElementCollection
+    {
+        public void Add(ConnectionStringSettings settings);
+        public void Remove(string name);
+    }
+
+    public sealed class ConfigurationPropertyAttribute : Attribute
+    {
+        public ConfigurationPropertyAttribute(string name);
+        public object DefaultValue { get; set; }
+        public bool IsKey { get; set; }
+        public bool IsRequired { get; set; }
+    }
+
+    public class ConfigurationErrorsException : Exception
+    {
+        public ConfigurationErrorsException();
+        public ConfigurationErrorsException(string message);
+    }
+}
+
+namespace System.Web
+{
+    public interface IHttpHandler
+    {
+        bool IsReusable { get; }
+        void ProcessRequest(HttpContext context);
+    }
+
+    public interface IHttpModule
+    {
+        void Dispose();
+        void Init(HttpApplication context);
+    }
+
+    public sealed class HttpContext : IServiceProvider
+    {
+        public HttpContext(HttpRequest request, HttpResponse response);
+        public static HttpContext Current { get; set; }
+        public HttpApplicationState Application { get; }
+        public IDictionary Items { get; }
+        public HttpRequest Request { get; }
+        public HttpResponse Response { get; }
+        public HttpServerUtility Server { get; }
+        public HttpSessionState Session { get; }
+        public object GetService(Type serviceType);
+    }
+
+    public sealed class HttpRequest
+    {
+        public string ApplicationPath { get; }
+        public HttpCookieCollection Cookies { get; }
+        public NameValueCollection Form { get; }
+        public NameValueCollection Headers { get; }
+        public string HttpMethod { get; }
+        public bool IsAuthenticated { get; }
+        public string Path { get; }
+        public NameValueCollection QueryString { get; }
+        public NameValueCollection ServerVariables { get; }
+        public Uri Url { get; }
+        public string UserHostAddress { get; }
+        public string MapPath(string virtualPath);
+    }
+
+    public sealed class HttpResponse
+    {
+        public string ContentType { get; set; }
+        public HttpCookieCollection Cookies { get; }
+        public int StatusCode { get; set; }
+        public void AddHeader(string name, string value);
+        public void Clear();
+        public void End();
+        public void Redirect(string url);
+        public void Redirect(string url, bool endResponse);
+        public void Write(string s);
+    }
+
+    public sealed class HttpServerUtility
+    {
+        public string HtmlDecode(string s);
+        public string HtmlEncode(string s);
+        public string MapPath(string path);
+        public void Transfer(string path);
+        public string UrlEncode(string s);
+    }
+
+    public sealed class HttpApplicationState
+    {
+        public object this[string name] { get; set; }
+        public void Lock();
+        public void UnLock();
+    }
+
+    public class HttpApplication : IHttpHandler
+    {
+        public HttpApplication();
+        public HttpContext Context { get; }
+        public HttpRequest Request { get; }
+        public HttpResponse Response { get; }
+        public HttpServerUtility Server { get; }
+        public bool IsReusable { get; }
+        public void ProcessRequest(HttpContext context);
+        public event EventHandler BeginRequest;
+        public event EventHandler EndRequest;
+    }
+
+    public sealed class HttpCookie
+    {
+        public HttpCookie(string name);
+        public HttpCookie(string name, string value);
+        public DateTime Expires { get; set; }
+        public bool HttpOnly { get; set; }
+        public string Name { get; set; }
+        public string Value { get; set; }
+    }
+
+    public sealed class HttpCookieCollection
+    {
+        public void Add(HttpCookie cookie);
+        public HttpCookie Get(string name);
+        public void Remove(string name);
+    }
+
+    public static class HttpRuntime
+    {
+        public static string AppDomainAppPath { get; }
+        public static Cache Cache { get; }
+    }
+
+    public static class HttpUtility
+    {
+        public static string HtmlDecode(string s);
+        public static string HtmlEncode(string s);
+        public static NameValueCollection ParseQueryString(string query);
+        public static string UrlDecode(string str);
+        public static string UrlEncode(string str);
+    }
+
+    public class HttpException : Exception
+    {
+        public HttpException();
+        public HttpException(string message);
+        public HttpException(int httpCode, string message);
+        public int GetHttpCode();
+    }
+}
+
+namespace System.Web.Caching
+{
+    public sealed class Cache
+    {
+        public object Get(string key);
+        public void Insert(string key, object value);
+        public object Remove(string key);
+    }
+}
+
+namespace System.Web.SessionState
+{
+    public sealed class HttpSessionState : ICollection
+    {
+        public int Count { get; }
+        public string SessionID { get; }
+        public int Timeout { get; set; }
+        public void Abandon();
+        public void Add(string name, object value);
+        public void Clear();
+        public void Remove(string name);
+    }
+}
+
+namespace System.Web.UI
+{
+    public class Control : IDisposable
+    {
+        public Control();
+        public virtual ControlCollection Controls { get; }
+        public virtual string ID { get; set; }
+        public virtual Page Page { get; set; }
+        public virtual bool Visible { get; set; }
+        public virtual void DataBind();
+        public void Dispose();
+        public virtual Control FindControl(string id);
+        protected virtual void OnInit(EventArgs e);
+        protected virtual void OnLoad(EventArgs e);
+        protected virtual void OnPreRender(EventArgs e);
+        protected virtual void Render(HtmlTextWriter writer);
+        public event EventHandler Init;
+        public event EventHandler Load;
+        public event EventHandler PreRender;
+    }
+
+    public class ControlCollection : ICollection
+    {
+        public ControlCollection(Control owner);
+        public virtual int Count { get; }
+        public virtual void Add(Control child);
+        public virtual void Clear();
+        public virtual void Remove(Control value);
+    }
+
+    public class TemplateControl : Control
+    {
+        public Control LoadControl(string virtualPath);
+    }
+
+    public class Page : TemplateControl, IHttpHandler
+    {
+        public Page();
+        public HttpApplicationState Application { get; }
+        public bool IsPostBack { get; }
+        public bool IsValid { get; }
+        public bool IsReusable { get; }
+        public virtual MasterPage Master { get; }
+        public HttpRequest Request { get; }
+        public HttpResponse Response { get; }
+        public HttpServerUtility Server { get; }
+        public virtual HttpSessionState Session { get; }
+        public string Title { get; set; }
+        public ClientScriptManager ClientScript { get; }
+        public virtual void ProcessRequest(HttpContext context);
+        public virtual void Validate();
+        protected virtual void OnPreInit(EventArgs e);
+    }
+
+    public class UserControl : TemplateControl
+    {
+        public HttpRequest Request { get; }
+        public HttpResponse Response { get; }
+        public HttpSessionState Session { get; }
+    }
+
+    public class MasterPage : UserControl
+    {
+        public MasterPage();
+    }
+
+    public sealed class ClientScriptManager
+    {
+        public void RegisterClientScriptBlock(Type type, string key, string script);
+        public void RegisterStartupScript(Type type, string key, string script);
+    }
+
+    public class HtmlTextWriter : TextWriter
+    {
+        public HtmlTextWriter(TextWriter writer);
+        public virtual void RenderBeginTag(string tagName);
+        public virtual void RenderEndTag();
+    }
+}
+
+namespace System.Web.UI.WebControls
+{
+    public class WebControl : Control
+    {
+        public virtual string CssClass { get; set; }
+        public virtual bool Enabled { get; set; }
+    }
+
+    public class Label : WebControl
+    {
+        public Label();
+        public virtual string Text { get; set; }
+    }
+
+    public class TextBox : WebControl
+    {
+        public TextBox();
+        public virtual string Text { get; set; }
+    }
+
+    public class Button : WebControl
+    {
+        public Button();
+        public string CommandArgument { get; set; }
+        public string Text { get; set; }
+        public event EventHandler Click;
+    }
+
+    public class GridView : WebControl
+    {
+        public GridView();
+        public virtual object DataSource { get; set; }
+    }
+}
+
+namespace System.Web.Services
+{
+    public class WebService
+    {
+        public WebService();
+        public HttpContext Context { get; }
+        public HttpSessionState Session { get; }
+    }
+
+    public sealed class WebMethodAttribute : Attribute
+    {
+        public WebMethodAttribute();
+        public WebMethodAttribute(bool enableSession);
+        public string Description { get; set; }
+        public bool EnableSession { get; set; }
+    }
+
+    public sealed class WebServiceAttribute : Attribute
+    {
+        public WebServiceAttribute();
+        public string Name { get; set; }
+        public string Namespace { get; set; }
+    }
+}
+#endif
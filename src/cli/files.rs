@@ -0,0 +1,33 @@
+use std::path::Path;
+use std::path::PathBuf;
+use anyhow::Context;
+
+/// The files with one of the given extensions in a directory and its subdirectories, sorted by
+/// name in every directory and with the files of a subdirectory where the subdirectory is.
+/// Hidden directories, such as `.git` and `.vs`, are left out if `skip_hidden` is set.
+pub fn files_with_extensions(path: &Path, extensions: &[&str], skip_hidden: bool) -> anyhow::Result<Vec<PathBuf>> {
+    let mut files = vec![];
+    let mut entries: Vec<PathBuf> = std::fs::read_dir(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<_, _>>()?;
+    entries.sort();
+    for entry in entries {
+        if entry.is_dir() {
+            if !(skip_hidden && is_hidden(&entry)) {
+                files.extend(files_with_extensions(&entry, extensions, skip_hidden)?);
+            }
+        } else if extension(&entry).is_some_and(|extension| extensions.contains(&extension)) {
+            files.push(entry);
+        }
+    }
+    Ok(files)
+}
+
+pub fn extension(path: &Path) -> Option<&str> {
+    path.extension().and_then(|e| e.to_str())
+}
+
+fn is_hidden(path: &Path) -> bool {
+    path.file_name().and_then(|name| name.to_str()).is_some_and(|name| name.starts_with('.'))
+}
@@ -1,15 +1,26 @@
+use std::path::Path;
 use std::path::PathBuf;
 use std::vec;
+use anyhow::anyhow;
 use anyhow::Error;
 use anyhow::Ok;
 use clap::Args;
 use clap::Parser;
 use clap;
+use stack_graphs::stitching::ForwardPartialPathStitcher;
+use stack_graphs::stitching::StitcherConfig;
+use stack_graphs::storage::FileStatus;
 use stack_graphs::storage::SQLiteReader;
+use stack_graphs::NoCancellation;
 use tree_sitter_stack_graphs::cli::database::DatabaseArgs;
+use tree_sitter_stack_graphs::loader::LanguageConfiguration;
 
+use crate::cli::query::NodeType;
 use crate::cli::query::Querier;
 use crate::cli::query::Query;
+use crate::cli::results::write_results;
+use crate::cli::results::OutputFormat;
+use crate::cli::results::Result;
 
 #[derive(Parser)]
 pub struct FindNode {
@@ -20,9 +31,11 @@ pub struct FindNode {
 }
 
 impl FindNode{
-    pub fn run(self, default_db_path: PathBuf) -> anyhow::Result<()> {
+    pub fn run(self, default_db_path: PathBuf, configurations: Vec<LanguageConfiguration>) -> anyhow::Result<()> {
         let db_path = self.db_args.get_or(default_db_path);
-        return self.find_node_args.run(&db_path);
+        let output = self.find_node_args.output;
+        let results = self.find_node_args.run(&db_path, &configurations)?;
+        write_results(&results, output, std::io::stdout().lock())
     }
 }
 
@@ -30,40 +43,73 @@ impl FindNode{
 #[derive(Debug)]
 pub struct FindNodeArgs {
     
-    #[clap(long, short = 't')]
-    pub node_type: String,
+    /// Kind of symbol to search for.
+    #[clap(long, short = 't', value_enum)]
+    pub node_type: NodeType,
     #[clap(long, short = 'r', required = true)]
     pub regex: String,
+    /// Format the results are printed in.
+    #[clap(long, short = 'o', value_enum, default_value_t = OutputFormat::Text)]
+    pub output: OutputFormat,
 }
 
 impl FindNodeArgs {
-    pub fn run(self, db_path: &PathBuf) -> anyhow::Result<()>{
-        let mut db = SQLiteReader::open(&db_path)?;
+    /// Runs the query against every file in the database and returns the incidents found.
+    /// References may resolve to the builtins of the given language configurations, such as the
+    /// types of the .NET class library.
+    pub fn run(self, db_path: &Path, configurations: &[LanguageConfiguration]) -> anyhow::Result<Vec<Result>>{
+        let mut db = SQLiteReader::open(db_path)?;
 
         let paths = Self::get_file_strings(&mut db)?;
 
         for path in paths {
-            let _ = db.load_graph_for_file(path.as_str())?;
+            db.load_graph_for_file(path.as_str())?;
+        }
+        for lc in configurations {
+            Self::load_builtins(&mut db, lc)?;
         }
-        let (graph, _, _) = db.get();
-
-        let mut q = Querier::new(graph);
 
-        let res = q.query(self.regex);
+        let mut q = Querier::new(&mut db);
 
+        q.query(self.node_type, self.regex)
+    }
 
+    /// Adds the builtins of a language to the loaded graph, together with their partial paths,
+    /// which are not stored in the database when indexing.
+    fn load_builtins(db: &mut SQLiteReader, lc: &LanguageConfiguration) -> anyhow::Result<()> {
+        let (graph, partials, database) = db.get();
+        let files = graph
+            .add_from_graph(&lc.builtins)
+            .map_err(|file| anyhow!("Duplicate builtins file {}", graph[file].name()))?;
+        for file in files {
+            ForwardPartialPathStitcher::find_minimal_partial_path_set_in_file(
+                graph,
+                partials,
+                file,
+                StitcherConfig::default(),
+                &NoCancellation,
+                |graph, partials, path| {
+                    database.add_partial_path(graph, partials, path.clone());
+                },
+            )?;
+        }
         Ok(())
     }
 
+    /// The files in the database that were indexed, leaving out those that failed to index,
+    /// which have no graph to load.
     fn get_file_strings(db: &mut SQLiteReader) -> anyhow::Result<Vec<String>, Error>{
         let mut file_strings: Vec<String>  = vec![];
         let mut files = db.list_all()?;
         for file in files.try_iter()?{
             let entry = file?;
+            if let FileStatus::Error(_) = entry.status {
+                continue;
+            }
             let file_path= entry.path.into_os_string().into_string().unwrap();
             file_strings.push(file_path);
         }
-        return Ok(file_strings)
+        Ok(file_strings)
     }
 
-}
\ No newline at end of file
+}
@@ -0,0 +1,364 @@
+use std::collections::BTreeMap;
+use std::collections::HashSet;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+use anyhow::anyhow;
+use anyhow::Context;
+use base64::prelude::BASE64_STANDARD_NO_PAD;
+use base64::Engine;
+use clap::Args;
+use clap::Parser;
+use clap::ValueHint;
+use sha1::Digest;
+use sha1::Sha1;
+use stack_graphs::arena::Handle;
+use stack_graphs::graph::File;
+use stack_graphs::graph::StackGraph;
+use stack_graphs::partial::PartialPaths;
+use stack_graphs::stitching::ForwardPartialPathStitcher;
+use stack_graphs::stitching::StitcherConfig;
+use stack_graphs::storage::FileStatus;
+use stack_graphs::storage::SQLiteWriter;
+use tree_sitter_stack_graphs::cli::database::DatabaseArgs;
+use tree_sitter_stack_graphs::loader::LanguageConfiguration;
+use tree_sitter_stack_graphs::CancelAfterDuration;
+use tree_sitter_stack_graphs::CancellationFlag;
+use tree_sitter_stack_graphs::NoCancellation;
+use tree_sitter_stack_graphs::Variables;
+use tree_sitter_stack_graphs::FILE_PATH_VAR;
+use tree_sitter_stack_graphs::ROOT_PATH_VAR;
+
+use crate::cli::files::files_with_extensions;
+use crate::cli::project::Project;
+use crate::cli::project::solution_projects;
+use crate::cli::project::Projects;
+
+/// Name of the global variable with the name of the project that a file is in.
+pub const PROJECT_NAME_VAR: &str = "PROJECT_NAME";
+
+/// The directory that the links between projects are stored under in the database. Like the
+/// root of external file sets, it is not a path on disk.
+pub const PROJECTS_ROOT: &str = "<projects>";
+
+#[derive(Parser)]
+pub struct Index {
+    #[clap(flatten)]
+    db_args: DatabaseArgs,
+    #[clap(flatten)]
+    index_args: IndexArgs,
+}
+
+impl Index {
+    pub fn run(self, default_db_path: PathBuf, configurations: Vec<LanguageConfiguration>) -> anyhow::Result<()> {
+        let db_path = self.db_args.get_or(default_db_path);
+        let lc = configurations.first().ok_or_else(|| anyhow!("No language configuration"))?;
+        self.index_args.run(&db_path, lc)
+    }
+}
+
+/// Indexes C# sources, each file as part of the project it is in. The declarations of a project
+/// are only visible to the project itself and to the projects that reference it.
+#[derive(Args)]
+#[derive(Debug)]
+pub struct IndexArgs {
+    /// Source files or directories to index, or solution (`.sln`) and project (`.csproj`) files,
+    /// whose projects are indexed.
+    #[clap(value_name = "SOURCE_PATH", required = true, value_hint = ValueHint::AnyPath)]
+    pub source_paths: Vec<PathBuf>,
+    /// Index files even if they are already present in the database.
+    #[clap(long, short = 'f')]
+    pub force: bool,
+    /// Print the status of every file, rather than only of the files that failed.
+    #[clap(long, short = 'v')]
+    pub verbose: bool,
+    /// Continue indexing from the given file, skipping the files before it.
+    #[clap(long, value_name = "SOURCE_PATH", value_hint = ValueHint::AnyPath)]
+    pub continue_from: Option<PathBuf>,
+    /// Hide the details of the errors of files that fail to index.
+    #[clap(long)]
+    pub hide_error_details: bool,
+    /// Maximum time to index a file for, in seconds. A file that takes longer is stored as
+    /// timed out.
+    #[clap(long, value_name = "SECONDS", value_parser = duration_from_seconds_str)]
+    pub max_file_time: Option<Duration>,
+    /// Print the number of files of every status, and the size of the graphs indexed, at the end.
+    #[clap(long)]
+    pub stats: bool,
+}
+
+impl IndexArgs {
+    pub fn run(&self, db_path: &Path, lc: &LanguageConfiguration) -> anyhow::Result<()> {
+        let source_paths = self.source_paths.iter()
+            .map(|path| path.canonicalize().with_context(|| format!("Failed to find {}", path.display())))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let projects = Projects::discover(&source_paths)?;
+
+        let mut db = SQLiteWriter::open(db_path)?;
+        for project in projects.iter() {
+            Self::index_project_references(&mut db, &projects, project)?;
+        }
+        // A file is indexed once, even if it is in more than one of the paths, with the first
+        // path that has it as its source root.
+        let mut files: Vec<(PathBuf, PathBuf)> = vec![];
+        for source_path in &source_paths {
+            match source_path.extension().and_then(|e| e.to_str()) {
+                Some("sln") => {
+                    let solution_projects = solution_projects(source_path)?;
+                    for project in projects.iter().filter(|project| solution_projects.contains(&project.path)) {
+                        files.extend(Self::project_files(project, &parent(source_path))?);
+                    }
+                }
+                Some("csproj") => {
+                    for project in projects.iter().filter(|project| project.path == *source_path) {
+                        files.extend(Self::project_files(project, &parent(source_path))?);
+                    }
+                }
+                _ if source_path.is_dir() => {
+                    for project in projects.iter().filter(|project| project.root.starts_with(source_path)) {
+                        files.extend(Self::project_files(project, source_path)?);
+                    }
+                    files.extend(Self::source_files(source_path)?.into_iter().map(|file| (file, source_path.clone())));
+                }
+                _ => files.push((source_path.clone(), parent(source_path))),
+            }
+        }
+        let mut continue_from = self.continue_from.as_ref()
+            .map(|path| path.canonicalize().with_context(|| format!("Failed to find {}", path.display())))
+            .transpose()?;
+        let mut stats = IndexStats::default();
+        let mut indexed = HashSet::new();
+        for (file, source_root) in files {
+            if !indexed.insert(file.clone()) {
+                continue;
+            }
+            if continue_from.as_ref().is_some_and(|continue_from| *continue_from != file) {
+                self.report(&file, "skipped", &mut stats);
+                continue;
+            }
+            continue_from = None;
+            if projects.is_excluded(&file) {
+                // the file may have been compiled when it was indexed before
+                db.clean_file(&file)?;
+                self.report(&file, "excluded", &mut stats);
+                continue;
+            }
+            let project = projects.project_for_file(&file);
+            self.index_file(&mut db, lc, &file, project, &source_root, &mut stats)
+                .with_context(|| format!("Failed to index {}. To continue indexing from this file later, add: --continue-from {}", file.display(), file.display()))?;
+        }
+        if self.stats {
+            stats.print();
+        }
+        Ok(())
+    }
+
+    /// The files that a project compiles, which may be outside of its directory, and the files in
+    /// its directory, which are excluded if it does not compile them. Each file is paired with the
+    /// source root it is indexed with.
+    fn project_files(project: &Project, source_root: &Path) -> anyhow::Result<Vec<(PathBuf, PathBuf)>> {
+        Ok(project.files.iter().cloned()
+            .chain(Self::source_files(&project.root)?)
+            .map(|file| (file, source_root.to_path_buf()))
+            .collect())
+    }
+
+    /// The C# files in a directory and its subdirectories.
+    fn source_files(path: &Path) -> anyhow::Result<Vec<PathBuf>> {
+        files_with_extensions(path, &["cs"], false)
+    }
+
+    /// Indexes a file as part of the project that compiles it, or as part of no project with the
+    /// directory being indexed as its root. A file that is indexed already is only indexed again if it changed or
+    /// indexing is forced, so a file that moved to another project needs forcing.
+    fn index_file(&self, db: &mut SQLiteWriter, lc: &LanguageConfiguration, path: &Path, project: Option<&Project>, source_root: &Path, stats: &mut IndexStats) -> anyhow::Result<()> {
+        let (project_name, root) = match project {
+            Some(project) => (project.name.as_str(), project.root.as_path()),
+            None => ("", source_root),
+        };
+        let source = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let tag = sha1(&source);
+        let status = match db.status_for_file(&path.to_string_lossy(), Some(&tag))? {
+            FileStatus::Missing => "indexed",
+            _ if self.force => "reindexed",
+            FileStatus::Indexed => {
+                self.report(path, "cached index", stats);
+                return Ok(());
+            }
+            FileStatus::Error(error) => {
+                self.report(path, &format!("cached error ({})", error), stats);
+                return Ok(());
+            }
+        };
+
+        let mut globals = Variables::new();
+        globals
+            .add(FILE_PATH_VAR.into(), path.to_string_lossy().as_ref().into())
+            .expect("failed to add file path variable");
+        globals
+            .add(ROOT_PATH_VAR.into(), root.to_string_lossy().as_ref().into())
+            .expect("failed to add root path variable");
+        globals
+            .add(PROJECT_NAME_VAR.into(), project_name.into())
+            .expect("failed to add project name variable");
+        let file_cancellation_flag = CancelAfterDuration::from_option(self.max_file_time);
+        match store_source(db, lc, path, &source, &globals, &tag, file_cancellation_flag.as_ref()) {
+            Ok(graph_stats) => stats.add(&graph_stats),
+            Err(_) if file_cancellation_flag.check("indexing file").is_err() => {
+                eprintln!("{}: timed out", path.display());
+                store_error(db, path, &tag, "timed out")?;
+                stats.count("timed out");
+                return Ok(());
+            }
+            Err(err) => {
+                if self.hide_error_details {
+                    eprintln!("{}: failed", path.display());
+                } else {
+                    eprintln!("{}: failed: {}", path.display(), err);
+                }
+                store_error(db, path, &tag, "failed")?;
+                stats.count("failed");
+                return Ok(());
+            }
+        }
+        self.report(path, status, stats);
+        Ok(())
+    }
+
+    fn report(&self, path: &Path, status: &str, stats: &mut IndexStats) {
+        if self.verbose {
+            println!("{}: {}", path.display(), status);
+        }
+        // cached errors are counted together, whatever the error was
+        stats.count(status.split(" (").next().unwrap_or(status));
+    }
+
+    /// Stores the links from a project to the projects it references, which make the declarations
+    /// of the referenced projects visible to it.
+    fn index_project_references(db: &mut SQLiteWriter, projects: &Projects, project: &Project) -> anyhow::Result<()> {
+        let path = Path::new(PROJECTS_ROOT).join(&project.name);
+        db.clean_file(&path)?;
+        let references = projects.reference_names(project);
+        if references.is_empty() {
+            return Ok(());
+        }
+
+        let mut graph = StackGraph::new();
+        let file = graph
+            .add_file(&path.to_string_lossy())
+            .expect("file not present in empty graph");
+        let symbol = graph.add_symbol(&format!("<project {}>", project.name));
+        let id = graph.new_node_id(file);
+        let project_node = graph
+            .add_pop_symbol_node(id, symbol, false)
+            .expect("node not present in empty graph");
+        graph.add_edge(StackGraph::root_node(), project_node, 0);
+        for reference in &references {
+            let symbol = graph.add_symbol(&format!("<project {}>", reference));
+            let id = graph.new_node_id(file);
+            let reference_node = graph
+                .add_push_symbol_node(id, symbol, false)
+                .expect("node not present in empty graph");
+            graph.add_edge(project_node, reference_node, 0);
+            graph.add_edge(reference_node, StackGraph::root_node(), 0);
+        }
+        store_graph(db, &graph, file, &sha1(&references.join(";")), &NoCancellation)?;
+        Ok(())
+    }
+}
+
+/// What indexing did: the number of files of every status, and the size of the graphs that were
+/// indexed.
+#[derive(Default)]
+struct IndexStats {
+    files: BTreeMap<String, usize>,
+    graph: GraphStats,
+}
+
+impl IndexStats {
+    fn count(&mut self, status: &str) {
+        *self.files.entry(status.to_string()).or_default() += 1;
+    }
+
+    fn add(&mut self, graph: &GraphStats) {
+        self.graph.nodes += graph.nodes;
+        self.graph.edges += graph.edges;
+        self.graph.paths += graph.paths;
+    }
+
+    fn print(&self) {
+        println!();
+        for (status, count) in &self.files {
+            println!("{:>8} {}", count, status);
+        }
+        println!("{:>8} graph nodes", self.graph.nodes);
+        println!("{:>8} graph edges", self.graph.edges);
+        println!("{:>8} partial paths", self.graph.paths);
+    }
+}
+
+/// The size of the stack graph of a file, and the number of its partial paths.
+#[derive(Default)]
+pub struct GraphStats {
+    pub nodes: usize,
+    pub edges: usize,
+    pub paths: usize,
+}
+
+/// Builds the stack graph of a source file with the given globals, and stores it in the database
+/// together with its partial paths. Building the graph and its paths stops with an error once the
+/// cancellation flag is set.
+pub fn store_source(db: &mut SQLiteWriter, lc: &LanguageConfiguration, path: &Path, source: &str, globals: &Variables, tag: &str, cancellation_flag: &dyn CancellationFlag) -> anyhow::Result<GraphStats> {
+    let mut graph = StackGraph::new();
+    let file = graph
+        .add_file(&path.to_string_lossy())
+        .expect("file not present in empty graph");
+    lc.sgl
+        .build_stack_graph_into(&mut graph, file, source, globals, cancellation_flag)
+        .map_err(|err| anyhow!("Failed to index {}: {}", path.display(), err))?;
+    store_graph(db, &graph, file, tag, cancellation_flag)
+}
+
+/// Stores that a file failed to index, in place of what was stored for it before, which may be
+/// an older version of the file.
+fn store_error(db: &mut SQLiteWriter, path: &Path, tag: &str, error: &str) -> anyhow::Result<()> {
+    db.clean_file(path)?;
+    db.store_error_for_file(path, tag, error)?;
+    Ok(())
+}
+
+/// Stores the stack graph of a file in the database, together with its partial paths.
+fn store_graph(db: &mut SQLiteWriter, graph: &StackGraph, file: Handle<File>, tag: &str, cancellation_flag: &dyn CancellationFlag) -> anyhow::Result<GraphStats> {
+    let mut partials = PartialPaths::new();
+    let mut paths = vec![];
+    ForwardPartialPathStitcher::find_minimal_partial_path_set_in_file(
+        graph,
+        &mut partials,
+        file,
+        StitcherConfig::default(),
+        &cancellation_flag,
+        |_, _, path| paths.push(path.clone()),
+    )?;
+    db.store_result_for_file(graph, file, tag, &mut partials, &paths)?;
+    let nodes: Vec<_> = graph.nodes_for_file(file).collect();
+    let edges = nodes.iter().map(|node| graph.outgoing_edges(*node).count()).sum();
+    Ok(GraphStats{nodes: nodes.len(), edges, paths: paths.len()})
+}
+
+fn duration_from_seconds_str(seconds: &str) -> anyhow::Result<Duration> {
+    Ok(Duration::from_secs(seconds.parse()?))
+}
+
+fn parent(path: &Path) -> PathBuf {
+    path.parent().unwrap_or(Path::new("")).to_path_buf()
+}
+
+/// The tag of a file's source, which tells whether the file has changed since it was indexed. It is
+/// the same as the tag of the `index` command of tree-sitter-stack-graphs, so that the files can
+/// be queried with its `query` command.
+pub fn sha1(value: &str) -> String {
+    let mut hasher = Sha1::new();
+    hasher.update(value);
+    BASE64_STANDARD_NO_PAD.encode(hasher.finalize())
+}
@@ -0,0 +1,105 @@
+use std::path::Path;
+use std::path::PathBuf;
+use anyhow::anyhow;
+use anyhow::Context;
+use clap::Args;
+use clap::Parser;
+use stack_graphs::storage::SQLiteWriter;
+use tree_sitter_stack_graphs::cli::database::DatabaseArgs;
+use tree_sitter_stack_graphs::loader::LanguageConfiguration;
+use tree_sitter_stack_graphs::NoCancellation;
+use tree_sitter_stack_graphs::Variables;
+use tree_sitter_stack_graphs::FILE_PATH_VAR;
+use tree_sitter_stack_graphs::ROOT_PATH_VAR;
+
+use crate::cli::files::files_with_extensions;
+use crate::cli::index::sha1;
+use crate::cli::index::store_source;
+use crate::cli::public_api;
+
+/// The directory that the files of external file sets are stored under in the database. Its name
+/// is not a path on disk, and definitions in external files are never reported as incidents.
+pub const EXTERNAL_ROOT: &str = "<external>";
+
+#[derive(Parser)]
+pub struct IndexExternal {
+    #[clap(flatten)]
+    db_args: DatabaseArgs,
+    #[clap(flatten)]
+    index_external_args: IndexExternalArgs,
+}
+
+impl IndexExternal {
+    pub fn run(self, default_db_path: PathBuf, configurations: Vec<LanguageConfiguration>) -> anyhow::Result<()> {
+        let db_path = self.db_args.get_or(default_db_path);
+        let lc = configurations.first().ok_or_else(|| anyhow!("No language configuration"))?;
+        let files = self.index_external_args.run(&db_path, lc)?;
+        println!("{}: indexed {} files", self.index_external_args.name, files);
+        Ok(())
+    }
+}
+
+/// Indexes the public API of a library, such as a NuGet package whose sources are not available,
+/// as a set of external files that references in indexed sources can resolve to.
+#[derive(Args)]
+#[derive(Debug)]
+pub struct IndexExternalArgs {
+    /// Name of the external file set, such as the name of the package. Indexing a set again
+    /// replaces everything indexed for it before.
+    #[clap(long, short = 'n')]
+    pub name: String,
+    /// Public API listings in the format of `PublicAPI.Shipped.txt`, C# reference stubs, or
+    /// directories containing either.
+    #[clap(required = true)]
+    pub paths: Vec<PathBuf>,
+}
+
+impl IndexExternalArgs {
+    /// Replaces the external file set in the database with the given listings and stubs, and
+    /// returns the number of files indexed.
+    pub fn run(&self, db_path: &Path, lc: &LanguageConfiguration) -> anyhow::Result<usize> {
+        let mut db = SQLiteWriter::open(db_path)?;
+        let set_root = Path::new(EXTERNAL_ROOT).join(&self.name);
+        db.clean_file_or_directory(&set_root)?;
+
+        let mut count = 0;
+        for path in &self.paths {
+            let root = if path.is_dir() { path.as_path() } else { path.parent().unwrap_or(Path::new("")) };
+            for file in Self::source_files(path)? {
+                let text = std::fs::read_to_string(&file)
+                    .with_context(|| format!("Failed to read {}", file.display()))?;
+                let source = match file.extension().and_then(|e| e.to_str()) {
+                    Some("txt") => public_api::to_csharp(&text),
+                    _ => text,
+                };
+                let relative = file.strip_prefix(root).unwrap_or(&file);
+                Self::index_source(&mut db, lc, &set_root, &set_root.join(relative), &source)?;
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+
+    /// The listings and stubs at `path`: the file itself, or the `.txt` and `.cs` files in the
+    /// directory and its subdirectories.
+    fn source_files(path: &Path) -> anyhow::Result<Vec<PathBuf>> {
+        if !path.is_dir() {
+            return Ok(vec![path.to_path_buf()]);
+        }
+        files_with_extensions(path, &["txt", "cs"], false)
+    }
+
+    /// Builds the stack graph of one external file and stores it with its partial paths, the same
+    /// way indexing a source file does.
+    fn index_source(db: &mut SQLiteWriter, lc: &LanguageConfiguration, root: &Path, path: &Path, source: &str) -> anyhow::Result<()> {
+        let mut globals = Variables::new();
+        globals
+            .add(FILE_PATH_VAR.into(), path.to_string_lossy().as_ref().into())
+            .expect("failed to add file path variable");
+        globals
+            .add(ROOT_PATH_VAR.into(), root.to_string_lossy().as_ref().into())
+            .expect("failed to add root path variable");
+        store_source(db, lc, path, source, &globals, &sha1(source), &NoCancellation)?;
+        Ok(())
+    }
+}
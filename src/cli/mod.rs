@@ -1,3 +1,9 @@
+pub mod files;
 pub mod find_node;
+pub mod index;
+pub mod index_external;
+pub mod project;
+pub mod public_api;
 pub mod query;
 pub mod results;
+pub mod signature;
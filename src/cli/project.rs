@@ -0,0 +1,321 @@
+use std::collections::BTreeSet;
+use std::path::Path;
+use std::path::PathBuf;
+use anyhow::Context;
+use regex::Regex;
+
+use crate::cli::files::extension;
+use crate::cli::files::files_with_extensions;
+
+/// A C# project, as declared by its `.csproj` file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Project {
+    /// Name of the project, which is the name of its project file without the extension.
+    pub name: String,
+    /// The project file.
+    pub path: PathBuf,
+    /// The directory of the project file, which the sources of the project are in.
+    pub root: PathBuf,
+    /// The project files of the projects that this project references.
+    pub references: Vec<PathBuf>,
+    /// The C# files that the project compiles, sorted.
+    pub files: Vec<PathBuf>,
+}
+
+/// The files that an SDK-style project leaves out of its default items, relative to its
+/// directory: the output and intermediate output directories, and hidden directories.
+const DEFAULT_ITEM_EXCLUDES: &[&str] = &["bin/**", "obj/**", "**/.*/**"];
+
+impl Project {
+    pub fn load(path: &Path) -> anyhow::Result<Project> {
+        let path = normalize(path);
+        let text = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let text = Regex::new(r#"(?s)<!--.*?-->"#).unwrap().replace_all(&text, "");
+        let root = path.parent().unwrap_or(Path::new("")).to_path_buf();
+        let references = items(&text, "ProjectReference")
+            .into_iter()
+            .flat_map(|item| item.include)
+            .map(|include| normalize(&root.join(include)))
+            .collect();
+        let files = Self::compile_items(&text, &root)?;
+        let name = path.file_stem().unwrap_or_default().to_string_lossy().to_string();
+        Ok(Project{name, path, root, references, files})
+    }
+
+    /// Evaluates the `Compile` items of a project file. An SDK-style project compiles the C# files
+    /// in its directory by default, unless `EnableDefaultCompileItems` or `EnableDefaultItems` is
+    /// `false`, and a legacy project compiles only the files it lists. `Compile` items then include
+    /// and remove files, in the order they appear in. Conditions are not evaluated, so every item
+    /// counts.
+    fn compile_items(text: &str, root: &Path) -> anyhow::Result<Vec<PathBuf>> {
+        let is_sdk_style = Regex::new(r#"<Project\s[^>]*\bSdk\s*=|<Sdk\s"#).unwrap().is_match(text);
+        let default_items = is_sdk_style
+            && !["EnableDefaultItems", "EnableDefaultCompileItems"].iter()
+                .any(|name| property(text, name).is_some_and(|value| value.eq_ignore_ascii_case("false")));
+
+        let mut files: BTreeSet<PathBuf> = BTreeSet::new();
+        if default_items {
+            let mut excludes: Vec<String> = DEFAULT_ITEM_EXCLUDES.iter().map(|exclude| exclude.to_string()).collect();
+            if let Some(value) = property(text, "DefaultItemExcludes") {
+                excludes.extend(split_item_list(&value));
+            }
+            let excludes = Glob::new_all(root, &excludes);
+            files.extend(Glob::new(root, "**/*.cs").files()?.into_iter().filter(|file| !excludes.iter().any(|glob| glob.matches(file))));
+        }
+        for item in items(text, "Compile") {
+            let excludes = Glob::new_all(root, &item.exclude);
+            for include in &item.include {
+                files.extend(Glob::new(root, include).files()?.into_iter().filter(|file| !excludes.iter().any(|glob| glob.matches(file))));
+            }
+            for remove in Glob::new_all(root, &item.remove) {
+                files.retain(|file| !remove.matches(file));
+            }
+        }
+        Ok(files.into_iter().collect())
+    }
+}
+
+/// The projects that the sources being indexed belong to.
+#[derive(Debug, Default)]
+pub struct Projects {
+    projects: Vec<Project>,
+}
+
+impl Projects {
+    /// Discovers the projects of the given paths: the projects of a solution file, a project
+    /// file itself, the project and solution files in a directory and its subdirectories, and the
+    /// project that a source file or directory is in.
+    pub fn discover(paths: &[PathBuf]) -> anyhow::Result<Projects> {
+        let mut projects = Projects::default();
+        for path in paths {
+            match extension(path) {
+                Some("sln") => projects.add_solution(path)?,
+                Some("csproj") => projects.add(path)?,
+                _ => {
+                    if path.is_dir() {
+                        projects.add_directory(path)?;
+                    }
+                    if let Some(project) = enclosing_project(path)? {
+                        projects.add(&project)?;
+                    }
+                }
+            }
+        }
+        Ok(projects)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Project> {
+        self.projects.iter()
+    }
+
+    /// The project that compiles a source file. A file that more than one project compiles, such
+    /// as a linked file, is part of the project with the closest directory that contains it, or
+    /// else of the first project that compiles it.
+    pub fn project_for_file(&self, file: &Path) -> Option<&Project> {
+        let mut projects = self.projects.iter()
+            .filter(|project| project.files.binary_search_by(|known| known.as_path().cmp(file)).is_ok());
+        let first = projects.next()?;
+        Some(std::iter::once(first).chain(projects)
+            .filter(|project| file.starts_with(&project.root))
+            .max_by_key(|project| project.root.components().count())
+            .unwrap_or(first))
+    }
+
+    /// Whether a source file is in the directory of a project but no project compiles it, such as
+    /// a generated file in `obj/` or a file the project removes.
+    pub fn is_excluded(&self, file: &Path) -> bool {
+        self.projects.iter().any(|project| file.starts_with(&project.root))
+            && self.project_for_file(file).is_none()
+    }
+
+    /// The names of the projects that a project references. A referenced project that was not
+    /// discovered is named after its project file all the same, as it may be indexed separately.
+    pub fn reference_names(&self, project: &Project) -> Vec<String> {
+        project.references.iter()
+            .map(|reference| match self.projects.iter().find(|project| &project.path == reference) {
+                Some(project) => project.name.clone(),
+                None => reference.file_stem().unwrap_or_default().to_string_lossy().to_string(),
+            })
+            .collect()
+    }
+
+    fn add(&mut self, path: &Path) -> anyhow::Result<()> {
+        let project = Project::load(path)?;
+        if !self.projects.iter().any(|known| known.path == project.path) {
+            self.projects.push(project);
+        }
+        Ok(())
+    }
+
+    fn add_solution(&mut self, path: &Path) -> anyhow::Result<()> {
+        for project in solution_projects(path)? {
+            self.add(&project)?;
+        }
+        Ok(())
+    }
+
+    fn add_directory(&mut self, path: &Path) -> anyhow::Result<()> {
+        for entry in files_with_extensions(path, &["sln", "csproj"], true)? {
+            match extension(&entry) {
+                Some("sln") => self.add_solution(&entry)?,
+                _ => self.add(&entry)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The project files a solution file lists. Projects that do not exist on disk are left out, as
+/// are the solution folders, which are listed like projects.
+pub fn solution_projects(path: &Path) -> anyhow::Result<Vec<PathBuf>> {
+    let text = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    let root = path.parent().unwrap_or(Path::new(""));
+    let project_regex = Regex::new(r#"(?m)^Project\("[^"]*"\)\s*=\s*"[^"]*"\s*,\s*"([^"]+)""#).unwrap();
+    Ok(project_regex.captures_iter(&text)
+        .map(|captures| normalize(&root.join(captures[1].replace('\\', "/"))))
+        .filter(|project| extension(project) == Some("csproj") && project.is_file())
+        .collect())
+}
+
+/// The project file in the closest directory that contains `path`, if any.
+fn enclosing_project(path: &Path) -> anyhow::Result<Option<PathBuf>> {
+    for directory in path.ancestors().skip(1) {
+        let Ok(entries) = std::fs::read_dir(directory) else {
+            continue;
+        };
+        let mut projects: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|entry| extension(entry) == Some("csproj"))
+            .collect();
+        projects.sort();
+        if let Some(project) = projects.into_iter().next() {
+            return Ok(Some(project));
+        }
+    }
+    Ok(None)
+}
+
+/// An item of a project file, with the paths in its `Include`, `Exclude` and `Remove` attributes.
+/// Paths that use MSBuild properties are left out, as their values are not known.
+#[derive(Debug, Default)]
+struct Item {
+    include: Vec<String>,
+    exclude: Vec<String>,
+    remove: Vec<String>,
+}
+
+/// The items of a kind in a project file, in the order they appear in.
+fn items(project: &str, kind: &str) -> Vec<Item> {
+    let item_regex = Regex::new(&format!(r#"<{}\s([^>]*)>"#, kind)).unwrap();
+    let attribute_regex = Regex::new(r#"\b(Include|Exclude|Remove)\s*=\s*"([^"]*)""#).unwrap();
+    item_regex.captures_iter(project)
+        .map(|item| {
+            let mut attributes = Item::default();
+            for attribute in attribute_regex.captures_iter(&item[1]) {
+                let paths = split_item_list(&attribute[2]);
+                match &attribute[1] {
+                    "Include" => attributes.include = paths,
+                    "Exclude" => attributes.exclude = paths,
+                    _ => attributes.remove = paths,
+                }
+            }
+            attributes
+        })
+        .collect()
+}
+
+/// The last value of a property in a project file.
+fn property(project: &str, name: &str) -> Option<String> {
+    let property_regex = Regex::new(&format!(r#"<{}(?:\s[^>]*)?>([^<]*)</{}>"#, name, name)).unwrap();
+    property_regex.captures_iter(project).last().map(|captures| captures[1].trim().to_string())
+}
+
+/// Splits the `;`-separated paths of an item or property, with `/` as directory separator.
+fn split_item_list(list: &str) -> Vec<String> {
+    list.split(';')
+        .map(|path| path.trim().replace('\\', "/"))
+        .filter(|path| !path.is_empty() && !path.contains("$("))
+        .collect()
+}
+
+/// A file pattern of a project file, relative to the project's directory, where `**` matches any
+/// number of directories, `*` any characters in a name and `?` a single character. Like MSBuild,
+/// patterns ignore case.
+pub struct Glob {
+    base: PathBuf,
+    regex: Regex,
+}
+
+impl Glob {
+    pub fn new(root: &Path, pattern: &str) -> Glob {
+        let path = normalize(&root.join(pattern));
+        let pattern = path.to_string_lossy().replace('\\', "/");
+        // the files a pattern matches are all in the directory before its first wildcard
+        let base: PathBuf = path.components()
+            .take_while(|component| !component.as_os_str().to_string_lossy().contains(['*', '?']))
+            .collect();
+        let mut regex = String::from("(?i)^");
+        let mut rest = pattern.as_str();
+        while let Some(c) = rest.chars().next() {
+            if let Some(after) = rest.strip_prefix("**/") {
+                regex.push_str("(?:.*/)?");
+                rest = after;
+                continue;
+            }
+            if let Some(after) = rest.strip_prefix("**") {
+                regex.push_str(".*");
+                rest = after;
+                continue;
+            }
+            match c {
+                '*' => regex.push_str("[^/]*"),
+                '?' => regex.push_str("[^/]"),
+                c => regex.push_str(&regex::escape(&c.to_string())),
+            }
+            rest = &rest[c.len_utf8()..];
+        }
+        regex.push('$');
+        Glob{base, regex: Regex::new(&regex).unwrap()}
+    }
+
+    fn new_all(root: &Path, patterns: &[String]) -> Vec<Glob> {
+        patterns.iter().map(|pattern| Glob::new(root, pattern)).collect()
+    }
+
+    pub fn matches(&self, path: &Path) -> bool {
+        self.regex.is_match(&path.to_string_lossy().replace('\\', "/"))
+    }
+
+    /// The existing C# files that the pattern matches.
+    pub fn files(&self) -> anyhow::Result<Vec<PathBuf>> {
+        if self.base.is_file() {
+            return Ok(if self.matches(&self.base) { vec![self.base.clone()] } else { vec![] });
+        }
+        if !self.base.is_dir() {
+            return Ok(vec![]);
+        }
+        let mut files = files_with_extensions(&self.base, &["cs"], false)?;
+        files.retain(|file| self.matches(file));
+        Ok(files)
+    }
+}
+
+/// The canonical path of an existing file, and otherwise the path without `.` and `..` segments.
+fn normalize(path: &Path) -> PathBuf {
+    if let Ok(path) = path.canonicalize() {
+        return path;
+    }
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            std::path::Component::CurDir => {}
+            std::path::Component::ParentDir => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
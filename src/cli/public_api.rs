@@ -0,0 +1,286 @@
+use std::collections::BTreeMap;
+
+/// Converts a public API listing, in the format of the `PublicAPI.Shipped.txt` and
+/// `PublicAPI.Unshipped.txt` files of the .NET public API analyzers, into C# declarations that can
+/// be indexed like reference source.
+///
+/// Every line of a listing names a type, or a member with its parameters and type:
+///
+/// ```text
+/// Newtonsoft.Json.JsonConvert
+/// static Newtonsoft.Json.JsonConvert.SerializeObject(object? value) -> string!
+/// Newtonsoft.Json.JsonSerializer.Formatting.get -> Newtonsoft.Json.Formatting
+/// Newtonsoft.Json.Formatting.Indented = 1 -> Newtonsoft.Json.Formatting
+/// ```
+///
+/// Listings do not say what kind of type a type is, nor what it derives from. A type with
+/// members whose value is of the type itself is an enum, a type named like `IName` is an
+/// interface, and any other type is a class. Operators and finalizers are left out.
+pub fn to_csharp(listing: &str) -> String {
+    let mut types: BTreeMap<String, TypeDeclaration> = BTreeMap::new();
+    let entries: Vec<Entry> = listing.lines().filter_map(Entry::parse).collect();
+    for entry in entries.iter().filter(|entry| entry.returns.is_none()) {
+        let declaration = types.entry(entry.signature.to_string()).or_default();
+        declaration.modifiers = entry.modifiers.iter().map(|m| m.to_string()).collect();
+    }
+    for entry in entries.iter().filter(|entry| entry.returns.is_some()) {
+        let (path, rest) = split_path(entry.signature);
+        let mut segments = split_segments(path);
+        let accessor = match segments.last() {
+            Some(&"get") | Some(&"set") => segments.pop(),
+            _ => None,
+        };
+        // An indexer's name, `this[...]`, has the parameters of the indexer.
+        let name = match segments.pop() {
+            None => continue,
+            Some(name) => clean_type(name),
+        };
+        let name = name.as_str();
+        if name.starts_with('~') || name == "operator" || rest.starts_with("operator") {
+            continue;
+        }
+        let owner = segments.join(".");
+        if owner.is_empty() {
+            continue;
+        }
+        let returns = clean_type(entry.returns.unwrap_or_default());
+        let modifiers: Vec<String> = entry.modifiers.iter().map(|m| m.to_string()).collect();
+        let declaration = types.entry(owner.clone()).or_default();
+        let member = if let Some(accessor) = accessor {
+            match declaration.members.iter_mut().find(|member| member.is_property(name)) {
+                Some(Member::Property{type_name, get, set, ..}) => {
+                    if accessor == "get" {
+                        *type_name = returns;
+                        *get = true;
+                    } else {
+                        *set = true;
+                    }
+                    continue;
+                }
+                _ => Member::Property{
+                    modifiers,
+                    type_name: if accessor == "get" { returns } else { "object".to_string() },
+                    name: name.to_string(),
+                    get: accessor == "get",
+                    set: accessor == "set",
+                },
+            }
+        } else if rest.starts_with('(') {
+            if strip_type_arguments(name) == strip_type_arguments(simple_name(&owner)) {
+                Member::Constructor{name: strip_type_arguments(name).to_string(), parameters: clean_type(rest)}
+            } else {
+                Member::Method{modifiers, returns, name: name.to_string(), parameters: clean_type(rest)}
+            }
+        } else if let Some(value) = rest.strip_prefix('=') {
+            if returns == owner {
+                Member::EnumValue{name: name.to_string(), value: value.trim().to_string()}
+            } else {
+                Member::Field{modifiers, type_name: returns, name: name.to_string(), value: Some(value.trim().to_string())}
+            }
+        } else {
+            Member::Field{modifiers, type_name: returns, name: name.to_string(), value: None}
+        };
+        declaration.members.push(member);
+    }
+
+    // A type is nested in the longest of its prefixes that is a type, and otherwise declared in
+    // the namespace its prefix names.
+    let mut nested: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    let mut namespaces: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for name in types.keys() {
+        let segments = split_segments(name);
+        let container = (1..segments.len()).rev()
+            .map(|length| segments[..length].join("."))
+            .find(|prefix| types.contains_key(prefix));
+        match container {
+            Some(container) => nested.entry(container).or_default().push(name.clone()),
+            None => namespaces.entry(segments[..segments.len() - 1].join(".")).or_default().push(name.clone()),
+        }
+    }
+
+    let mut source = String::new();
+    for (namespace, names) in &namespaces {
+        let indent = if namespace.is_empty() {
+            0
+        } else {
+            source.push_str(&format!("namespace {}\n{{\n", namespace));
+            1
+        };
+        for name in names {
+            write_type(&mut source, name, &types, &nested, indent);
+        }
+        if !namespace.is_empty() {
+            source.push_str("}\n");
+        }
+    }
+    source
+}
+
+const MODIFIERS: &[&str] = &["abstract", "const", "extern", "new", "override", "readonly", "sealed", "static", "virtual"];
+
+/// A line of a listing, with the modifiers in front of it and the type after its `->`, which
+/// only types do not have.
+struct Entry<'a> {
+    modifiers: Vec<&'a str>,
+    signature: &'a str,
+    returns: Option<&'a str>,
+}
+
+impl Entry<'_> {
+    fn parse(line: &str) -> Option<Entry<'_>> {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with("*REMOVED*") {
+            return None;
+        }
+        // `~` marks declarations that are oblivious to nullable reference types.
+        let line = line.strip_prefix('~').unwrap_or(line);
+        let (declaration, returns) = match line.rsplit_once(" -> ") {
+            None => (line, None),
+            Some((declaration, returns)) => (declaration, Some(returns.trim())),
+        };
+        let mut modifiers = vec![];
+        let mut signature = declaration.trim();
+        while let Some((first, rest)) = signature.split_once(' ') {
+            if !MODIFIERS.contains(&first) {
+                break;
+            }
+            modifiers.push(first);
+            signature = rest.trim_start();
+        }
+        Some(Entry{modifiers, signature, returns})
+    }
+}
+
+#[derive(Default)]
+struct TypeDeclaration {
+    modifiers: Vec<String>,
+    members: Vec<Member>,
+}
+
+enum Member {
+    Constructor{name: String, parameters: String},
+    Method{modifiers: Vec<String>, returns: String, name: String, parameters: String},
+    Property{modifiers: Vec<String>, type_name: String, name: String, get: bool, set: bool},
+    Field{modifiers: Vec<String>, type_name: String, name: String, value: Option<String>},
+    EnumValue{name: String, value: String},
+}
+
+impl Member {
+    fn is_property(&self, property: &str) -> bool {
+        matches!(self, Member::Property{name, ..} if name == property)
+    }
+
+    fn to_csharp(&self) -> String {
+        match self {
+            Member::Constructor{name, parameters} => format!("public {}{};", name, parameters),
+            Member::Method{modifiers, returns, name, parameters} => {
+                format!("public {}{} {}{};", prefix(modifiers), returns, name, parameters)
+            }
+            Member::Property{modifiers, type_name, name, get, set} => {
+                let accessors = match (get, set) {
+                    (true, true) => "get; set;",
+                    (false, true) => "set;",
+                    _ => "get;",
+                };
+                format!("public {}{} {} {{ {} }}", prefix(modifiers), type_name, name, accessors)
+            }
+            Member::Field{modifiers, type_name, name, value: Some(value)} => {
+                format!("public {}{} {} = {};", prefix(modifiers), type_name, name, value)
+            }
+            Member::Field{modifiers, type_name, name, value: None} => {
+                format!("public {}{} {};", prefix(modifiers), type_name, name)
+            }
+            Member::EnumValue{name, value} if value.is_empty() => format!("{},", name),
+            Member::EnumValue{name, value} => format!("{} = {},", name, value),
+        }
+    }
+}
+
+fn write_type(source: &mut String, name: &str, types: &BTreeMap<String, TypeDeclaration>, nested: &BTreeMap<String, Vec<String>>, indent: usize) {
+    let declaration = &types[name];
+    let simple_name = simple_name(name);
+    let is_enum = declaration.members.iter().any(|member| matches!(member, Member::EnumValue{..}));
+    let is_interface = {
+        let mut chars = simple_name.chars();
+        chars.next() == Some('I') && chars.next().is_some_and(|c| c.is_ascii_uppercase())
+    };
+    let (kind, members): (&str, Vec<&Member>) = if is_enum {
+        ("enum", declaration.members.iter().filter(|member| matches!(member, Member::EnumValue{..})).collect())
+    } else if is_interface {
+        ("interface", declaration.members.iter().collect())
+    } else {
+        ("class", declaration.members.iter().collect())
+    };
+    let padding = "    ".repeat(indent);
+    source.push_str(&format!("{}public {}{} {}\n{}{{\n", padding, prefix(&declaration.modifiers), kind, simple_name, padding));
+    for member in members {
+        source.push_str(&format!("{}    {}\n", padding, member.to_csharp()));
+    }
+    if !is_enum {
+        for name in nested.get(name).into_iter().flatten() {
+            write_type(source, name, types, nested, indent + 1);
+        }
+    }
+    source.push_str(&format!("{}}}\n", padding));
+}
+
+/// The modifiers of a declaration, followed by a space if there are any.
+fn prefix(modifiers: &[String]) -> String {
+    modifiers.iter().map(|modifier| format!("{} ", modifier)).collect()
+}
+
+/// Splits a member signature into the dotted path of the member's name and what follows it: a
+/// parameter list, an `= value`, or nothing.
+fn split_path(signature: &str) -> (&str, &str) {
+    let mut depth = 0;
+    for (i, c) in signature.char_indices() {
+        match c {
+            '<' | '[' => depth += 1,
+            '>' | ']' => depth -= 1,
+            '(' if depth == 0 => return (&signature[..i], &signature[i..]),
+            ' ' if depth == 0 => return (&signature[..i], signature[i..].trim_start()),
+            _ => {}
+        }
+    }
+    (signature, "")
+}
+
+/// Splits a dotted name at the dots that are not in type arguments or indexer parameters.
+fn split_segments(name: &str) -> Vec<&str> {
+    let mut segments = vec![];
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in name.char_indices() {
+        match c {
+            '<' | '[' | '(' => depth += 1,
+            '>' | ']' | ')' => depth -= 1,
+            '.' if depth == 0 => {
+                segments.push(&name[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    segments.push(&name[start..]);
+    segments
+}
+
+fn simple_name(name: &str) -> &str {
+    split_segments(name).last().copied().unwrap_or(name)
+}
+
+fn strip_type_arguments(name: &str) -> &str {
+    name.split('<').next().unwrap_or(name)
+}
+
+/// Removes the `!` annotations of non-nullable reference types, which are not C#.
+fn clean_type(text: &str) -> String {
+    let mut cleaned = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '!' || chars.peek() == Some(&'=') {
+            cleaned.push(c);
+        }
+    }
+    cleaned.trim().to_string()
+}
@@ -1,354 +1,927 @@
-use std::{any::Any, collections::HashMap, vec};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::Path;
 
-use anyhow::{Error, Ok};
-use http::{uri::PathAndQuery, Uri};
+use anyhow::{anyhow, Context, Error};
+use clap::ValueEnum;
 use url::Url;
 use regex::Regex;
-use stack_graphs::{arena::Handle, graph::{DebugEntry, Edge, File, Node, StackGraph}, stitching::Appendable};
-use crate::cli::results::{Location, Position, Result};
+use stack_graphs::{
+    arena::Handle,
+    graph::{Node, StackGraph},
+    partial::PartialPath,
+    stitching::{ForwardPartialPathStitcher, StitcherConfig},
+    storage::SQLiteReader,
+    NoCancellation,
+};
+use crate::cli::index::PROJECTS_ROOT;
+use crate::cli::index_external::EXTERNAL_ROOT;
+use crate::cli::results::{Location, Position, Result, Variable};
+use crate::cli::signature::{parse_signature, Argument, Arguments, Parameters};
+use crate::STACK_GRAPHS_BUILTINS_PATH;
 
 pub struct Querier<'a> {
-    db: &'a mut StackGraph,
+    db: &'a mut SQLiteReader,
+}
+
+/// The kind of symbol a query searches for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum NodeType {
+    /// Any reference to a symbol declared in the matching namespaces.
+    Namespace,
+    /// References to classes.
+    Class,
+    /// References to interfaces.
+    Interface,
+    /// References to structs.
+    Struct,
+    /// References to records.
+    Record,
+    /// References to enums.
+    Enum,
+    /// References to enum members.
+    EnumMember,
+    /// References to methods and constructors.
+    Method,
+    /// References to fields.
+    Field,
+    /// References to constants.
+    Constant,
+    /// References to properties.
+    Property,
+    /// References to events.
+    Event,
+    /// Types referenced in a type position, such as a declaration, cast or `new` expression.
+    TypeReference,
+    /// Methods referenced as the target of an invocation.
+    Invocation,
+    /// Types that derive from or implement the types the query names, directly or through
+    /// other types. The names of their declarations are reported.
+    DerivedType,
+    /// Members of derived classes, structs and records that implement the interface members or
+    /// other members the query names. The names of their declarations are reported.
+    Implementation,
+    /// Members declared `override` in derived types that override the virtual or abstract
+    /// members the query names. The names of their declarations are reported.
+    Override,
+    /// Declarations decorated with the attributes the query names, with or without their
+    /// `Attribute` suffix. The names of the declarations are reported.
+    Decorated,
 }
 
 pub trait Query {
-    fn query(&mut self, query: String) -> anyhow::Result<Vec<Result>, Error>;
+    fn query(&mut self, node_type: NodeType, query: String) -> anyhow::Result<Vec<Result>, Error>;
 }
 
 impl Query for Querier<'_> {
 
-    fn query(&mut self, query: String) -> anyhow::Result<Vec<Result>, Error> {
+    fn query(&mut self, node_type: NodeType, query: String) -> anyhow::Result<Vec<Result>, Error> {
         let search: Search = self.get_search(query)?;
 
+        let (graph, _, _) = self.db.get();
+
+        // Find the definitions the search names, by walking down the namespaces, types and members
+        // that are reachable from the root node. (for instance namespace System.Configuration;
+        // class ConfigurationManager; method AppSettings)
+        let mut definitions: Vec<Definition> = vec![];
+        let mut visited: HashSet<Handle<Node>> = HashSet::from([StackGraph::root_node()]);
+        collect_definitions(graph, StackGraph::root_node(), &mut vec![], &mut vec![], &mut visited, &mut definitions);
+
+        match node_type {
+            NodeType::DerivedType => return self.derived_types(&search, &definitions),
+            NodeType::Implementation | NodeType::Override => return self.member_implementations(node_type, &search, &definitions),
+            NodeType::Decorated => return self.decorated(&search, &definitions),
+            _ => {}
+        }
+
+        let mut namespace_symbols = NamespaceSymbols::default();
+        for definition in &definitions {
+            if search.matches_definition(definition) {
+                let symbol = match definition.names.last() {
+                    None => continue,
+                    Some(symbol) => symbol,
+                };
+                namespace_symbols.insert(symbol, &definition.syntax_type, definition.node);
+            }
+        }
+
+        // Any reference with the name of one of the definitions could be a use of it, and so could
+        // any reference to a using alias. Which definitions it actually refers to is up to name
+        // resolution.
+        let aliases: HashSet<&str> = graph.iter_nodes()
+            .filter(|node| {
+                graph.source_info(*node)
+                    .and_then(|s| s.syntax_type.into_option())
+                    .is_some_and(|syntax_type| &graph[syntax_type] == "alias")
+            })
+            .filter_map(|node| graph[node].symbol().map(|symbol| &graph[symbol]))
+            .collect();
+        let mut candidates: Vec<(Handle<Node>, Vec<Handle<Node>>)> = vec![];
+        for node_handle in graph.iter_nodes() {
+            let node: &Node = &graph[node_handle];
+            if !node.is_reference() {
+                continue;
+            }
+            let symbol = match node.symbol() {
+                None => continue,
+                Some(symbol) => &graph[symbol],
+            };
+            let contexts = reference_contexts(graph, node_handle);
+            let symbol = if aliases.contains(symbol) { None } else { Some(symbol) };
+            let targets = namespace_symbols.definitions(symbol, node_type, &contexts);
+            if !targets.is_empty() {
+                candidates.push((node_handle, targets));
+            }
+        }
+
         let mut results: Vec<Result> = vec![];
+        for (reference, targets) in candidates {
+            let resolved = self.resolves_to(reference, &targets)?;
+            let (graph, _, _) = self.db.get();
+            // A name resolves to every overload of a method, so whether the reference calls the
+            // overload searched for is told by its arguments.
+            let arguments = match search.signature {
+                None => None,
+                Some(_) => invocation_arguments(graph, reference),
+            };
+            let called = resolved.iter().find(|definition| {
+                match (list_text(graph, **definition, "parameters"), &arguments) {
+                    (Some(parameters), Some(arguments)) => Parameters::parse(parameters).accepts(arguments),
+                    _ => true,
+                }
+            });
+            let definition = match called {
+                None => continue,
+                Some(called) => definitions.iter().find(|definition| definition.node == *called),
+            };
+            if let Some(result) = reference_result(graph, reference, definition) {
+                results.push(result);
+            }
+        }
+
+        // Sort and de-duplicate, so that callers get the same output for the same database
+        // regardless of the order in which nodes were visited.
+        results.sort_by(|a, b| a.sort_key().cmp(&b.sort_key()));
+        results.dedup_by(|a, b| a.sort_key() == b.sort_key());
+        Ok(results)
+    }
+}
+
+impl Querier<'_> {
+    pub fn new(db: &mut SQLiteReader) -> Querier<'_> {
+        Querier{db}
+    }
+    fn get_search(&self, query: String) -> anyhow::Result<Search, Error> {
+       Search::create_search(query)
+    }
+
+    /// Finds the types that derive from or implement the types `search` names.
+    fn derived_types(&mut self, search: &Search, definitions: &[Definition]) -> anyhow::Result<Vec<Result>, Error> {
+        let types: Vec<Handle<Node>> = definitions.iter()
+            .filter(|definition| TYPE_SYNTAX_TYPES.contains(&definition.syntax_type.as_str()))
+            .filter(|definition| search.matches_definition(definition))
+            .map(|definition| definition.node)
+            .collect();
+        let derived = self.derived_from(types, definitions, &mut HashMap::new())?;
+        let (graph, _, _) = self.db.get();
+        Ok(definition_results(graph, derived, definitions))
+    }
 
-        // If we are search for all things from a ref
-        // ex: System.Configuration.ConfigurationManager.* or System.Configuration.*
-        // this means that we need to find the Nodes from the namespace, then find all the matches
-        // for all the nodes in that namespace.
-        if search.all_references_search() {
-            // get all the compilation units that use some portion of the search (using System or using System.Configuration)
-            // This will require us to then determine if there qualified names ConfigurationManager.AppSettings for examples;
-
-            // We will also need to find the definition of this by looking at the namepsace declartion. then we need to capture all the nodes that are
-            // definitions attached to this (for instance namespace System.Configuration; Class ConfigurationManager; method AppSettings)
-            let mut definition_root_nodes: Vec<Handle<Node>> = vec![];
-            let mut referenced_files: Vec<Handle<File>> = vec![];
-            let mut file_to_compunit_handle: HashMap<Handle<File>, Handle<Node>> = HashMap::new();
-
-            for node_handle in self.db.iter_nodes() {
-                let node: &Node = &self.db[node_handle];
-                let symbol_option = node.symbol();
-                if symbol_option.is_none() {
-                    // If the node doesn't have a symbol to look at, then we should continue
-                    // and it only used to tie together other nodes.
+    /// Finds the members of derived types that implement or override the members `search`
+    /// names: those with the same name and kind, and for methods the same parameter types. Only
+    /// members declared `override` are overrides, and only members of types that are not
+    /// interfaces are implementations.
+    fn member_implementations(&mut self, node_type: NodeType, search: &Search, definitions: &[Definition]) -> anyhow::Result<Vec<Result>, Error> {
+        // Every declaration of a partial type is a definition of its own, and only one of them
+        // may name the base types, so a type is all the declarations with its name.
+        let mut by_name: HashMap<(&[String], &[String]), Vec<&Definition>> = HashMap::new();
+        for definition in definitions.iter().filter(|definition| TYPE_SYNTAX_TYPES.contains(&definition.syntax_type.as_str())) {
+            by_name.entry((definition.namespace.as_slice(), definition.names.as_slice())).or_default().push(definition);
+        }
+        let type_of = |member: &Definition| -> Vec<&Definition> {
+            match member.names.split_last() {
+                None => vec![],
+                Some((_, type_names)) => by_name.get(&(member.namespace.as_slice(), type_names)).cloned().unwrap_or_default(),
+            }
+        };
+
+        // The members of a type share its derived types, and the types share the resolved base
+        // types, so that each base type reference is resolved once.
+        let mut resolved_bases: HashMap<Handle<Node>, Vec<Handle<Node>>> = HashMap::new();
+        let mut derived_types: HashMap<Vec<Handle<Node>>, Vec<Handle<Node>>> = HashMap::new();
+        let mut found: Vec<Handle<Node>> = vec![];
+        for member in definitions.iter().filter(|definition| MEMBER_SYNTAX_TYPES.contains(&definition.syntax_type.as_str())) {
+            if !search.matches_definition(member) {
+                continue;
+            }
+            let declaring_type: Vec<Handle<Node>> = type_of(member).iter().map(|declaration| declaration.node).collect();
+            if declaring_type.is_empty() {
+                continue;
+            }
+            if !derived_types.contains_key(&declaring_type) {
+                let derived = self.derived_from(declaring_type.clone(), definitions, &mut resolved_bases)?;
+                derived_types.insert(declaring_type.clone(), derived);
+            }
+            let derived = &derived_types[&declaring_type];
+            let (graph, _, _) = self.db.get();
+            for candidate in definitions {
+                if candidate.syntax_type != member.syntax_type || candidate.names.last() != member.names.last() {
                     continue;
                 }
-                
-                let symbol = &self.db[node.symbol().unwrap()];
-                let source_info = self.db.source_info(node_handle);
-                if source_info.is_none() {
-                    println!("continue source_info: {}", node.display(self.db));
-                    continue
+                let candidate_type = type_of(candidate);
+                if !candidate_type.iter().any(|declaration| derived.contains(&declaration.node)) {
+                    continue;
                 }
-                match source_info.unwrap().syntax_type.into_option() {
-                    None => continue,
-                    Some(handle) => {
-                        let syntax_type = &self.db[handle];
-                        match syntax_type {
-                            "comp-unit" => {
-                                let filepath = node.file();
-                                match filepath {
-                                    None => continue,
-                                    Some(file_handle) => {
-                                        file_to_compunit_handle.insert(file_handle, node_handle);
-
-                                    }
-                                }
-                            }
-                            "import" => {
-                                if search.partial_namespace(symbol) {
-                                    let filepath = node.file();
-                                    match filepath {
-                                        None => continue,
-                                        Some(file_handle) => {
-                                            referenced_files.push(file_handle);
-                                        }
-                                    }
-                                }
-                            }
-                            "namespace-declaration" => {
-                                if search.match_namespace(symbol) {
-                                    definition_root_nodes.push(node_handle);
-                                }
-                                //TODO: Handle nested namespace declarations
-                            }
-                            &_ => {
-                                continue
-                            }
-                        }
+                if let (Some(parameters), Some(candidate_parameters)) = (&member.parameters, &candidate.parameters) {
+                    if !parameters.same_types(candidate_parameters) {
+                        continue;
                     }
                 }
+                let matches = match node_type {
+                    NodeType::Override => modifiers(graph, candidate.node).contains(&"override"),
+                    _ => candidate_type.iter().all(|declaration| declaration.syntax_type != "interface-def"),
+                };
+                if matches {
+                    found.push(candidate.node);
+                }
             }
-            // Now that we have the all the nodes we need to build the reference symbols to match the *
-            let namespace_symbols = NamespaceSymbols::new(self.db, definition_root_nodes)?;
-
-            for file in referenced_files {
-                let comp_unit_node = file_to_compunit_handle.get(&file);
-                if comp_unit_node.is_none() {
-                    println!("something went very wrong");
-                    break;
+        }
+        let (graph, _, _) = self.db.get();
+        Ok(definition_results(graph, found, definitions))
+    }
+
+    /// Finds the declarations decorated with an attribute class `search` names.
+    fn decorated(&mut self, search: &Search, definitions: &[Definition]) -> anyhow::Result<Vec<Result>, Error> {
+        let suffixed = search.with_attribute_suffix()?;
+        let classes: Vec<Handle<Node>> = definitions.iter()
+            .filter(|definition| definition.syntax_type == "class-def")
+            .filter(|definition| {
+                search.matches_definition(definition)
+                    || suffixed.as_ref().is_some_and(|suffixed| suffixed.matches_definition(definition))
+            })
+            .map(|definition| definition.node)
+            .collect();
+
+        // Every definition of a declaration has the attributes decorating it next to it, with
+        // edges to the references to their names.
+        let (graph, _, _) = self.db.get();
+        let mut decorations: Vec<(Handle<Node>, Handle<Node>)> = vec![];
+        for node in graph.iter_nodes().filter(|node| graph[*node].is_definition()) {
+            for attribute in graph.outgoing_edges(node).map(|edge| edge.sink) {
+                if !has_syntax_type(graph, attribute, "attribute") {
+                    continue;
                 }
-                let f = &self.db[file];
-                let file_url = Url::from_file_path(f.name());
-                if file_url.is_err() {
-                    println!("something went very wrong URI");
-                    break;
+                for edge in graph.outgoing_edges(attribute) {
+                    decorations.push((node, edge.sink));
                 }
-                let file_uri = file_url.unwrap().as_str().to_string();
-                let _ = f;
-                self.traverse_node_search(*comp_unit_node.unwrap(), &namespace_symbols, &mut results, file_uri);
             }
-
-            println!("{:?}", results)
         }
-        let mut r: Vec<Result> = vec![];
-        Ok(r)
 
+        let mut decorated: Vec<Handle<Node>> = vec![];
+        for (definition, reference) in decorations {
+            if !decorated.contains(&definition) && !self.resolves_to(reference, &classes)?.is_empty() {
+                decorated.push(definition);
+            }
+        }
+        let (graph, _, _) = self.db.get();
+        Ok(definition_results(graph, decorated, definitions))
     }
-}
 
-impl Querier<'_> {
-    pub fn new(db: &mut StackGraph) -> impl Query + use<'_> {
-        return Querier{db};
-    }
-    fn get_search(&self, query: String) -> anyhow::Result<Search, Error> {
-       return Search::create_search(query);
-    }
-    fn traverse_node_search(&mut self, node: Handle<Node>, namespace_symbols: &NamespaceSymbols, results: &mut Vec<Result>, file_uri: String) {
-        let mut traverse_nodes: Vec<Handle<Node>> = vec![];
-        for edge in self.db.outgoing_edges(node) {
-            traverse_nodes.push(edge.sink);
-            let child_node = &self.db[edge.sink];
-            match child_node.symbol() {
-                None => {
-                    continue
-                },
-                Some(symbol_handle) => {
-                    let symbol = &self.db[symbol_handle];
-                    if namespace_symbols.symbol_in_namespace(symbol.to_string()) {
-                        let debug_ndoe = self.db.node_debug_info(edge.sink).map_or(vec![], |d| {
-                            return d.iter().map(|e| {
-                                let k = self.db[e.key].to_string();
-                                let v = self.db[e.value].to_string();
-                                return (k, v)
-                            }).collect();
-                        });
-                        let edge_debug = self.db.edge_debug_info(edge.source, edge.sink).map_or(vec![], |d| {
-                            return d.iter().map(|e| {
-                                let k = self.db[e.key].to_string();
-                                let v = self.db[e.value].to_string();
-                                return (k, v)
-                            }).collect();
-                        });
-
-                        
-
-                        println!("{} -- {} - {:?} -- {:?}", symbol, child_node.display(self.db), debug_ndoe, edge_debug);
-                        let code_location: Location;
-                        let line_number: usize;
-                        match self.db.source_info(edge.sink) {
-                            None => {
-                                println!("something is wrong this shouldn't happen");
-                                continue;
-                            },
-                            Some(source_info) => {
-                                line_number = source_info.span.start.line;
-                                code_location = Location{
-                                    start_position: Position{
-                                        line: source_info.span.start.line,
-                                        character: source_info.span.start.column.utf8_offset,
-                                    },
-                                    end_position: Position { 
-                                        line: source_info.span.end.line,
-                                        character: source_info.span.end.column.utf8_offset,
-                                    },
-                                }
-                            }
-                        }
-                        let var: HashMap<String, Box<dyn Any +'static>> = HashMap::new();
-                        results.push(Result{file_uri: file_uri.clone(), line_number, code_location, variables: var});
+    /// Returns the type definitions that derive from or implement one of `types`, directly or
+    /// through other types, by resolving the base types of every type until no more derived
+    /// types are found. The types that base type references resolve to are kept in
+    /// `resolved_bases`, which may be shared by calls for different types.
+    fn derived_from(&mut self, mut types: Vec<Handle<Node>>, definitions: &[Definition], resolved_bases: &mut HashMap<Handle<Node>, Vec<Handle<Node>>>) -> anyhow::Result<Vec<Handle<Node>>, Error> {
+        let (graph, _, _) = self.db.get();
+        let type_nodes: Vec<Handle<Node>> = definitions.iter()
+            .filter(|definition| TYPE_SYNTAX_TYPES.contains(&definition.syntax_type.as_str()))
+            .map(|definition| definition.node)
+            .collect();
+        let bases: Vec<(Handle<Node>, Vec<Handle<Node>>)> = type_nodes.iter()
+            .map(|node| (*node, base_type_references(graph, *node)))
+            .collect();
+
+        let mut derived: Vec<Handle<Node>> = vec![];
+        loop {
+            let mut found = false;
+            for (definition, references) in &bases {
+                if derived.contains(definition) {
+                    continue;
+                }
+                for reference in references {
+                    if !resolved_bases.contains_key(reference) {
+                        let resolved = self.resolves_to(*reference, &type_nodes)?;
+                        resolved_bases.insert(*reference, resolved);
+                    }
+                    if resolved_bases[reference].iter().any(|base| types.contains(base)) {
+                        derived.push(*definition);
+                        types.push(*definition);
+                        found = true;
+                        break;
                     }
                 }
             }
+            if !found {
+                return Ok(derived);
+            }
         }
-        for n in traverse_nodes {
-            self.traverse_node_search(n, namespace_symbols, results, file_uri.clone());
+    }
+
+    /// Which of `definitions` `reference` resolves to, by stitching together the partial paths
+    /// in the database the same way `query definition` does.
+    fn resolves_to(&mut self, reference: Handle<Node>, definitions: &[Handle<Node>]) -> anyhow::Result<Vec<Handle<Node>>, Error> {
+        let mut paths: Vec<PartialPath> = vec![];
+        ForwardPartialPathStitcher::find_all_complete_partial_paths(
+            self.db,
+            std::iter::once(reference),
+            StitcherConfig::default().with_detect_similar_paths(true),
+            &NoCancellation,
+            |_, _, path| paths.push(path.clone()),
+        )?;
+        let (_, partials, _) = self.db.get();
+        // A path that is shadowed by another one, such as a member hidden by a nearer
+        // declaration, is not where the reference resolves to.
+        Ok(paths.iter()
+            .filter(|path| {
+                definitions.contains(&path.end_node)
+                    && paths.iter().all(|other| !other.shadows(partials, path))
+            })
+            .map(|path| path.end_node)
+            .collect())
+    }
+}
+
+/// Builds the result reporting the reference or definition `node`, unless it is in a file that
+/// is not a source file, such as the builtins or an external file set. `definition` is the
+/// definition that the node is or refers to, if it is known.
+fn reference_result(graph: &StackGraph, node: Handle<Node>, definition: Option<&Definition>) -> Option<Result> {
+    let file = graph[node].file()?;
+    if !is_source_file(graph[file].name()) {
+        return None;
+    }
+    let file_uri = Url::from_file_path(graph[file].name()).ok()?;
+    let source_info = graph.source_info(node)?;
+    let code_location = Location{
+        start_position: Position{
+            line: source_info.span.start.line,
+            character: source_info.span.start.column.utf8_offset,
+        },
+        end_position: Position {
+            line: source_info.span.end.line,
+            character: source_info.span.end.column.utf8_offset,
+        },
+    };
+    Some(Result{
+        file_uri: file_uri.as_str().to_string(),
+        line_number: source_info.span.start.line + 1,
+        code_location,
+        variables: result_variables(graph, node, definition),
+    })
+}
+
+/// The variables of the result reporting `node`: the `name` it has in the source, and the
+/// `fullyQualifiedName` and `nodeType` of the definition it is or refers to. The name differs from
+/// the last segment of the fully qualified name for a reference to a using alias.
+fn result_variables(graph: &StackGraph, node: Handle<Node>, definition: Option<&Definition>) -> BTreeMap<String, Variable> {
+    let mut variables = BTreeMap::new();
+    if let Some(symbol) = graph[node].symbol() {
+        variables.insert("name".to_string(), Variable::String(graph[symbol].to_string()));
+    }
+    if let Some(definition) = definition {
+        variables.insert("fullyQualifiedName".to_string(), Variable::String(definition.qualified_name()));
+        if let Some(node_type) = definition.node_type().and_then(|node_type| node_type.to_possible_value()) {
+            variables.insert("nodeType".to_string(), Variable::String(node_type.get_name().to_string()));
         }
     }
+    variables
 }
 
-pub struct NamespaceSymbols {
-    classes: HashMap<String, Handle<Node>>,
-    class_fields: HashMap<String, Handle<Node>>,
-    class_methods: HashMap<String, Handle<Node>>,
+/// Whether the file named `name` is an indexed source file, rather than the builtins, a file of
+/// an external file set or the links between projects.
+fn is_source_file(name: &str) -> bool {
+    let path = Path::new(name);
+    name != STACK_GRAPHS_BUILTINS_PATH
+        && !path.starts_with(EXTERNAL_ROOT)
+        && !path.starts_with(PROJECTS_ROOT)
 }
 
-impl NamespaceSymbols {
-    fn new(db: &mut StackGraph, nodes: Vec<Handle<Node>>) -> anyhow::Result<NamespaceSymbols, Error> {
-        let mut classes: HashMap<String, Handle<Node>> = HashMap::new();
-        let mut class_fields: HashMap<String, Handle<Node>> = HashMap::new();
-        let mut class_methods: HashMap<String, Handle<Node>> = HashMap::new();
-
-        for node_handle in nodes {
-            //Get all the edges
-            Self::traverse_node(db, node_handle, &mut classes, &mut class_fields, &mut class_methods)
+/// The text of the list, such as the parameter list, that is kept next to `node` with the
+/// syntax type `syntax_type`.
+fn list_text<'a>(graph: &'a StackGraph, node: Handle<Node>, syntax_type: &str) -> Option<&'a str> {
+    graph.outgoing_edges(node).find_map(|edge| {
+        let list_syntax_type = graph.source_info(edge.sink)?.syntax_type.into_option()?;
+        if &graph[list_syntax_type] != syntax_type {
+            return None;
         }
+        graph[edge.sink].symbol().map(|symbol| &graph[symbol])
+    })
+}
 
-        println!("{:?}", classes);
-        println!("{:?}", class_methods);
-        println!("{:?}", class_fields);
-        Ok(NamespaceSymbols {classes: classes, class_fields: class_fields, class_methods: class_methods })
+/// The arguments of the invocation whose method is the reference `node`, from the tags kept next
+/// to it: one tag for every argument, ordered by the precedence of its edge, with tags for what
+/// is known about the argument below it.
+fn invocation_arguments(graph: &StackGraph, node: Handle<Node>) -> Option<Arguments> {
+    let list = graph.outgoing_edges(node)
+        .map(|edge| edge.sink)
+        .find(|sink| has_syntax_type(graph, *sink, "arguments"))?;
+    let mut edges: Vec<_> = graph.outgoing_edges(list)
+        .filter(|edge| has_syntax_type(graph, edge.sink, "argument"))
+        .collect();
+    edges.sort_by_key(|edge| edge.precedence);
+    let arguments: Vec<Argument> = edges.into_iter()
+        .map(|edge| {
+            let tags: Vec<(&str, &str)> = graph.outgoing_edges(edge.sink)
+                .filter_map(|edge| {
+                    let syntax_type = graph.source_info(edge.sink)?.syntax_type.into_option()?;
+                    let symbol = graph[edge.sink].symbol()?;
+                    Some((&graph[syntax_type], &graph[symbol]))
+                })
+                .collect();
+            let tag = |syntax_type: &str| tags.iter().find(|(s, _)| *s == syntax_type).map(|(_, text)| text.to_string());
+            if tag("named").is_some() {
+                Argument::Named
+            } else if tag("modifier").is_some() {
+                // An argument passed by reference is a variable, whose type is not known.
+                Argument::Unknown
+            } else if let Some(type_name) = tag("argument-type") {
+                Argument::Typed(type_name)
+            } else if let Some(literal) = tag("number") {
+                Argument::Number(literal)
+            } else {
+                Argument::Unknown
+            }
+        })
+        .collect();
+    Some(Arguments::new(&arguments))
+}
 
-    }
+/// Builds the sorted results reporting the definitions `nodes`, which are described by the
+/// matching `definitions`.
+fn definition_results(graph: &StackGraph, nodes: Vec<Handle<Node>>, definitions: &[Definition]) -> Vec<Result> {
+    let mut results: Vec<Result> = nodes.into_iter()
+        .filter_map(|node| {
+            let definition = definitions.iter().find(|definition| definition.node == node);
+            reference_result(graph, node, definition)
+        })
+        .collect();
+    results.sort_by(|a, b| a.sort_key().cmp(&b.sort_key()));
+    results.dedup_by(|a, b| a.sort_key() == b.sort_key());
+    results
+}
 
-    fn traverse_node(db: &mut StackGraph, node: Handle<Node>, classes: &mut HashMap<String, Handle<Node>>, class_fields: &mut HashMap<String, Handle<Node>>, class_methods: &mut HashMap<String, Handle<Node>>) {
-        let mut child_edges: Vec<Handle<Node>> = vec![];
-        for edge in db.outgoing_edges(node) {
-            child_edges.push(edge.sink);
-            let child_node = &db[edge.sink];
-            let symbol = match child_node.symbol() {
-                None => continue,
-                Some(symbol) => &db[symbol]
-            };
-            match db.source_info(edge.sink) {
-                None => {
-                    continue
-                },
-                Some(source_info) => {
-                    match source_info.syntax_type.into_option() {
-                        None => {
-                            continue
-                        },
-                        Some(syntax_type) => {
-                            match &db[syntax_type] {
-                                "method_name" => {
-                                    class_methods.insert(symbol.to_string(), edge.sink);
-                                }
-                                "class-def" => {
-                                    classes.insert(symbol.to_string(), edge.sink);
-                                }
-                                &_ => {},
-                            }
-                        }
-                    }
-                }
+/// Whether `node` has the syntax type `syntax_type`.
+fn has_syntax_type(graph: &StackGraph, node: Handle<Node>, syntax_type: &str) -> bool {
+    graph.source_info(node)
+        .and_then(|s| s.syntax_type.into_option())
+        .is_some_and(|s| &graph[s] == syntax_type)
+}
+
+/// The contexts the reference `node` appears in, such as `invocation`, from the tags that are
+/// kept next to it.
+fn reference_contexts(graph: &StackGraph, node: Handle<Node>) -> Vec<&str> {
+    graph.outgoing_edges(node)
+        .filter_map(|edge| graph.source_info(edge.sink)?.syntax_type.into_option())
+        .map(|syntax_type| &graph[syntax_type])
+        .filter(|syntax_type| REFERENCE_CONTEXTS.contains(syntax_type))
+        .collect()
+}
+
+/// The modifiers, such as `override`, that are kept next to the member definition `node`.
+fn modifiers(graph: &StackGraph, node: Handle<Node>) -> Vec<&str> {
+    graph.outgoing_edges(node)
+        .filter(|edge| {
+            graph.source_info(edge.sink)
+                .and_then(|s| s.syntax_type.into_option())
+                .is_some_and(|syntax_type| &graph[syntax_type] == "modifier")
+        })
+        .filter_map(|edge| graph[edge.sink].symbol().map(|symbol| &graph[symbol]))
+        .collect()
+}
+
+/// The syntax types of the members that derived types can implement or override.
+const MEMBER_SYNTAX_TYPES: &[&str] = &["method_name", "property", "event"];
+
+/// The syntax types of type definitions.
+const TYPE_SYNTAX_TYPES: &[&str] = &["class-def", "interface-def", "struct-def", "record-def", "enum-def"];
+
+/// The syntax types of the tags that tell the context of a reference.
+const REFERENCE_CONTEXTS: &[&str] = &["invocation", "type-reference", "base-type", "attribute"];
+
+/// The references to the base types of the type definition `node`. They are found below the
+/// type's members, behind the `.` pushed on top of each base type's name.
+fn base_type_references(graph: &StackGraph, node: Handle<Node>) -> Vec<Handle<Node>> {
+    let mut references: Vec<Handle<Node>> = vec![];
+    let mut visited: HashSet<Handle<Node>> = HashSet::new();
+    let mut pending: Vec<Handle<Node>> = vec![node];
+    while let Some(node) = pending.pop() {
+        for edge in graph.outgoing_edges(node) {
+            if !visited.insert(edge.sink) {
+                continue;
+            }
+            let sink = &graph[edge.sink];
+            let symbol = sink.symbol().map(|symbol| &graph[symbol]);
+            match sink {
+                Node::PushSymbol(_) if reference_contexts(graph, edge.sink).contains(&"base-type") => references.push(edge.sink),
+                // The type's own `.`, its members and the `.` pushed for each base type.
+                Node::Scope(_) => pending.push(edge.sink),
+                Node::PopSymbol(_) | Node::PushSymbol(_) if symbol == Some(".") => pending.push(edge.sink),
+                _ => {}
             }
+        }
+    }
+    references
+}
+
+/// A definition that can be reached from the root node, together with the names leading to it.
+struct Definition {
+    node: Handle<Node>,
+    syntax_type: String,
+    /// The segments of the namespace the definition is declared in.
+    namespace: Vec<String>,
+    /// The names of the types and members the definition is nested in, ending with its own.
+    names: Vec<String>,
+    /// The parameters of a method definition.
+    parameters: Option<Parameters>,
+}
 
+impl Definition {
+    /// The namespace and names of the definition joined by `.`, as in `System.String.Join`.
+    fn qualified_name(&self) -> String {
+        [self.namespace.as_slice(), self.names.as_slice()].concat().join(".")
+    }
+
+    /// The kind of symbol the definition declares.
+    fn node_type(&self) -> Option<NodeType> {
+        match self.syntax_type.as_str() {
+            "class-def" => Some(NodeType::Class),
+            "interface-def" => Some(NodeType::Interface),
+            "struct-def" => Some(NodeType::Struct),
+            "record-def" => Some(NodeType::Record),
+            "enum-def" => Some(NodeType::Enum),
+            "enum-member" => Some(NodeType::EnumMember),
+            "method_name" => Some(NodeType::Method),
+            "field" => Some(NodeType::Field),
+            "constant" => Some(NodeType::Constant),
+            "property" => Some(NodeType::Property),
+            "event" => Some(NodeType::Event),
+            _ => None,
         }
-        for child_edge in child_edges {
-            Self::traverse_node(db, child_edge, classes, class_fields, class_methods);
+    }
+}
+
+/// Walks the pop nodes and scopes below `node`, collecting the definitions of types and members
+/// with the names of the namespaces and declarations that contain them.
+fn collect_definitions(graph: &StackGraph, node: Handle<Node>, namespace: &mut Vec<String>, names: &mut Vec<String>, visited: &mut HashSet<Handle<Node>>, definitions: &mut Vec<Definition>) {
+    for edge in graph.outgoing_edges(node) {
+        if !visited.insert(edge.sink) {
+            continue;
+        }
+        let sink = &graph[edge.sink];
+        match sink {
+            Node::Scope(_) => collect_definitions(graph, edge.sink, namespace, names, visited, definitions),
+            Node::PopSymbol(_) => {
+                let symbol = match sink.symbol() {
+                    None => continue,
+                    Some(symbol) => &graph[symbol],
+                };
+                let syntax_type = match graph.source_info(edge.sink).and_then(|s| s.syntax_type.into_option()) {
+                    None => "",
+                    Some(syntax_type) => &graph[syntax_type],
+                };
+                // The declarations of a project are reached through the project's symbol.
+                if symbol == "." || syntax_type == "project" {
+                    collect_definitions(graph, edge.sink, namespace, names, visited, definitions);
+                } else if syntax_type == "namespace-declaration" && names.is_empty() {
+                    namespace.push(symbol.to_string());
+                    collect_definitions(graph, edge.sink, namespace, names, visited, definitions);
+                    namespace.pop();
+                } else if sink.is_definition() {
+                    names.push(symbol.to_string());
+                    definitions.push(Definition{
+                        node: edge.sink,
+                        syntax_type: syntax_type.to_string(),
+                        namespace: namespace.clone(),
+                        names: names.clone(),
+                        parameters: list_text(graph, edge.sink, "parameters").map(Parameters::parse),
+                    });
+                    collect_definitions(graph, edge.sink, namespace, names, visited, definitions);
+                    names.pop();
+                }
+            }
+            // Anything else, such as an import or a reference, leads out of the declaration.
+            _ => {}
         }
     }
+}
 
-    fn symbol_in_namespace(&self, symbol: String) -> bool {
-        let class_match = self.classes.get(&symbol);
-        let method_match = self.class_methods.get(&symbol);
-        let field_match = self.class_fields.get(&symbol);
+/// The definitions a search names, by kind and by name.
+#[derive(Default)]
+pub struct NamespaceSymbols {
+    classes: HashMap<String, Vec<Handle<Node>>>,
+    interfaces: HashMap<String, Vec<Handle<Node>>>,
+    structs: HashMap<String, Vec<Handle<Node>>>,
+    records: HashMap<String, Vec<Handle<Node>>>,
+    enums: HashMap<String, Vec<Handle<Node>>>,
+    enum_members: HashMap<String, Vec<Handle<Node>>>,
+    class_fields: HashMap<String, Vec<Handle<Node>>>,
+    class_constants: HashMap<String, Vec<Handle<Node>>>,
+    class_methods: HashMap<String, Vec<Handle<Node>>>,
+    class_properties: HashMap<String, Vec<Handle<Node>>>,
+    class_events: HashMap<String, Vec<Handle<Node>>>,
+}
 
-        if class_match.is_some() || method_match.is_some() || field_match.is_some() {
-            return true
-        }
-        return false
+impl NamespaceSymbols {
+    fn insert(&mut self, symbol: &str, syntax_type: &str, node: Handle<Node>) {
+        let symbols = match syntax_type {
+            "method_name" => &mut self.class_methods,
+            "class-def" => &mut self.classes,
+            "interface-def" => &mut self.interfaces,
+            "struct-def" => &mut self.structs,
+            "record-def" => &mut self.records,
+            "enum-def" => &mut self.enums,
+            "enum-member" => &mut self.enum_members,
+            "field" => &mut self.class_fields,
+            "constant" => &mut self.class_constants,
+            "property" => &mut self.class_properties,
+            "event" => &mut self.class_events,
+            &_ => return,
+        };
+        symbols.entry(symbol.to_string()).or_default().push(node);
+    }
 
+    /// The definitions of the kind selected by `node_type` that a reference to `symbol`, with
+    /// in the given contexts, could be a use of. A reference to a using alias could be a use of
+    /// a definition with any name, which is selected by passing no symbol.
+    fn definitions(&self, symbol: Option<&str>, node_type: NodeType, contexts: &[&str]) -> Vec<Handle<Node>> {
+        let types = vec![&self.classes, &self.interfaces, &self.structs, &self.records, &self.enums];
+        let symbols: Vec<&HashMap<String, Vec<Handle<Node>>>> = match node_type {
+            NodeType::Namespace => [types, vec![&self.enum_members, &self.class_methods, &self.class_fields, &self.class_constants, &self.class_properties, &self.class_events]].concat(),
+            NodeType::Class => vec![&self.classes],
+            NodeType::Interface => vec![&self.interfaces],
+            NodeType::Struct => vec![&self.structs],
+            NodeType::Record => vec![&self.records],
+            NodeType::Enum => vec![&self.enums],
+            NodeType::EnumMember => vec![&self.enum_members],
+            NodeType::Method => vec![&self.class_methods],
+            NodeType::Field => vec![&self.class_fields],
+            NodeType::Constant => vec![&self.class_constants],
+            NodeType::Property => vec![&self.class_properties],
+            NodeType::Event => vec![&self.class_events],
+            NodeType::TypeReference if contexts.iter().any(|c| ["type-reference", "base-type", "attribute"].contains(c)) => types,
+            NodeType::Invocation if contexts.contains(&"invocation") => vec![&self.class_methods],
+            NodeType::TypeReference | NodeType::Invocation => vec![],
+            NodeType::DerivedType | NodeType::Implementation | NodeType::Override | NodeType::Decorated => vec![],
+        };
+        symbols.into_iter()
+            .flat_map(|symbols| match symbol {
+                None => symbols.values().flatten().copied().collect::<Vec<_>>(),
+                Some(symbol) => symbols.get(symbol).into_iter().flatten().copied().collect(),
+            })
+            .collect()
     }
 }
 
 
+#[derive(Clone)]
 struct SearchPart {
     part: String,
     regex: Option<Regex>
 }
 
-struct Search {
+/// A parsed query.
+///
+/// A query is a list of segments separated by `.`, each of which must match a whole namespace,
+/// type or member name:
+///
+/// - `Name` matches the name exactly.
+/// - `Config*` is a glob, where `*` matches any number of characters and `?` a single one.
+/// - `/Config(uration)?Manager/` is a regular expression. It may contain `.`, and a `/` inside
+///   it is written as `\/`.
+/// - `**` matches any number of namespace segments, including none.
+///
+/// A trailing `*` or `**` searches for everything declared in what the preceding segments name.
+///
+/// A method name may be followed by the parameter types of an overload, as in
+/// `Ns.Class.Method(string, int)`. Only that overload is searched for then, and only the
+/// invocations that can call it: by the types of their arguments where those are known, and by
+/// the number of arguments otherwise.
+pub struct Search {
     parts: Vec<SearchPart>,
+    /// The parameter types of the overload searched for, if given.
+    signature: Option<Vec<String>>,
 }
 
 impl Search {
-    fn create_search(query: String) -> anyhow::Result<Search, Error> {
+    pub fn create_search(query: String) -> anyhow::Result<Search, Error> {
+        let (name, signature) = Self::split_signature(&query)?;
         let mut parts: Vec<SearchPart> = vec![];
-        for part in query.split(".") {
-            if part.contains("*") {
-                let regex: Regex;
-                if part == "*" {
-                    regex = Regex::new(".*")?;
-                } else {
-                    regex = Regex::new(part)?;
-                }
+        for part in Self::split_query(name)? {
+            parts.push(SearchPart::new(part)?);
+        }
+
+        let search = Search{parts, signature: signature.map(parse_signature)};
+        if search.signature.is_some() && search.all_references_search() {
+            return Err(anyhow!("a signature must follow a method name in query '{}'", query));
+        }
+        Ok(search)
+    }
+
+    /// The normalized parameter types of the overload the search names, if it names one.
+    pub fn signature(&self) -> Option<&[String]> {
+        self.signature.as_deref()
+    }
+
+    /// Splits a trailing parameter list, as in `Method(string, int)`, off the query.
+    pub fn split_signature(query: &str) -> anyhow::Result<(&str, Option<&str>), Error> {
+        if !query.ends_with(')') || query.ends_with("/)") {
+            return Ok((query, None));
+        }
+        let mut depth = 0;
+        for (i, c) in query.char_indices().rev() {
+            match c {
+                ')' => depth += 1,
+                '(' => depth -= 1,
+                _ => {}
+            }
+            if depth == 0 {
+                return Ok((&query[..i], Some(&query[i..])));
+            }
+        }
+        Err(anyhow!("unbalanced parentheses in signature of query '{}'", query))
+    }
 
-                parts.push(SearchPart{
-                    part: part.to_string(),
-                    regex: Some(regex),
+    /// Splits the query on the `.`s that are not part of a regular expression segment.
+    pub fn split_query(query: &str) -> anyhow::Result<Vec<&str>, Error> {
+        let mut segments: Vec<&str> = vec![];
+        let mut rest = query;
+        loop {
+            let end = if rest.starts_with('/') {
+                let mut escaped = false;
+                let close = rest.char_indices().skip(1).find(|(_, c)| {
+                    let found = !escaped && *c == '/';
+                    escaped = !escaped && *c == '\\';
+                    found
                 });
+                match close {
+                    None => return Err(anyhow!("unterminated regular expression in query '{}'", query)),
+                    Some((i, _)) => i + 1,
+                }
             } else {
-                parts.push(SearchPart { part: part.to_string(), regex: None })
+                rest.find('.').unwrap_or(rest.len())
+            };
+            let (segment, tail) = rest.split_at(end);
+            if segment.is_empty() {
+                return Err(anyhow!("empty segment in query '{}'", query));
+            }
+            segments.push(segment);
+            match tail.strip_prefix('.') {
+                Some(tail) => rest = tail,
+                None if tail.is_empty() => return Ok(segments),
+                None => return Err(anyhow!("expected '.' after regular expression in query '{}'", query)),
             }
         }
+    }
 
-        return Ok(Search{parts: parts})
+    /// The same search with `Attribute` appended to the name it ends with, for finding the
+    /// attribute classes an attribute can be named by. A search ending in `*` or `**` has no
+    /// such name.
+    fn with_attribute_suffix(&self) -> anyhow::Result<Option<Search>, Error> {
+        let (last, parts) = match self.parts.split_last() {
+            Some((last, parts)) if !self.all_references_search() => (last, parts),
+            _ => return Ok(None),
+        };
+        let suffixed = match last.part.strip_prefix('/').and_then(|p| p.strip_suffix('/')) {
+            Some(regex) => format!("/(?:{})Attribute/", regex),
+            None => format!("{}Attribute", last.part),
+        };
+        let mut parts = parts.to_vec();
+        parts.push(SearchPart::new(&suffixed)?);
+        Ok(Some(Search{parts, signature: None}))
     }
 
     fn all_references_search(&self) -> bool {
-        let last = self.parts.last();
-        match last {
-            None => {
-                return false;
-            }
-            Some(part) => {
-                if part.part == "*" {
-                    return true;
-                }
-                return false;
-            }
+        match self.parts.last() {
+            None => false,
+            Some(part) => part.part == "*" || part.is_any_segments(),
         }
     }
 
-    fn partial_namespace(&self, symbol: &str) -> bool {
-        // We will need to break apart the symbol based on "." then looping through, look at the same index, and if it matches continue
-        // if it doesnt then return false.
-        for (i, symbol_part) in symbol.split(".").enumerate() {
-            if !self.parts[i].matches(symbol_part.to_string()) {
-                return false;
+    /// The parts naming a definition, that is all parts except a trailing `*`.
+    fn definition_parts(&self) -> &[SearchPart] {
+        match self.parts.last() {
+            Some(part) if part.part == "*" => &self.parts[..self.parts.len()-1],
+            _ => &self.parts,
+        }
+    }
+
+    /// Whether the search names `definition`, or for a search ending in `*` or `**`, a
+    /// declaration that contains it.
+    fn matches_definition(&self, definition: &Definition) -> bool {
+        let namespace: Vec<&str> = definition.namespace.iter().map(String::as_str).collect();
+        let names: Vec<&str> = definition.names.iter().map(String::as_str).collect();
+        self.matches_names(&namespace, &names)
+            && (self.all_references_search() || self.matches_signature(definition))
+    }
+
+    /// Whether the search names the declaration with the given namespace segments and names of
+    /// the types and members it is nested in, ending with its own. A search ending in `*` or `**`
+    /// names the declarations inside the one it names.
+    pub fn matches_names(&self, namespace: &[&str], names: &[&str]) -> bool {
+        let parts = self.definition_parts();
+        Self::consumed_parts(parts, namespace).into_iter().any(|namespace_len| {
+            // `**` only spans namespace segments, so below the namespace it matches nothing.
+            let parts: Vec<&SearchPart> = parts[namespace_len..].iter()
+                .filter(|part| !part.is_any_segments())
+                .collect();
+            let names_match = parts.iter().zip(names).all(|(part, name)| part.matches(name));
+            if self.all_references_search() {
+                names_match && parts.len() < names.len()
+            } else {
+                names_match && parts.len() == names.len()
             }
+        })
+    }
+
+    /// Whether `definition` is the overload the search names, if it names one.
+    fn matches_signature(&self, definition: &Definition) -> bool {
+        match (&self.signature, &definition.parameters) {
+            (None, _) => true,
+            (Some(signature), Some(parameters)) => parameters.matches(signature),
+            (Some(_), None) => false,
         }
-        return true;
     }
-    
-    fn match_namespace(&self, symbol: &str) -> bool {
-        let symbol_parts:Vec<&str> = symbol.split(".").collect();
-        if symbol_parts.len() != self.parts.len()-1 {
-            return false;
+
+    /// Returns every number of leading `parts` that together match all of `segments`.
+    fn consumed_parts(parts: &[SearchPart], segments: &[&str]) -> Vec<usize> {
+        let mut consumed: Vec<usize> = vec![];
+        Self::consume(parts, 0, segments, &mut consumed);
+        consumed.sort();
+        consumed.dedup();
+        consumed
+    }
+
+    fn consume(parts: &[SearchPart], index: usize, segments: &[&str], consumed: &mut Vec<usize>) {
+        if segments.is_empty() {
+            consumed.push(index);
         }
-        for (i, symbol_part) in symbol_parts.iter().enumerate() {
-            if !self.parts[i].matches(symbol_part.to_string()) {
-                return false
+        let part = match parts.get(index) {
+            None => return,
+            Some(part) => part,
+        };
+        if part.is_any_segments() {
+            // `**` either matches no more segments, or the next one and possibly more.
+            Self::consume(parts, index + 1, segments, consumed);
+            if let Some((_, rest)) = segments.split_first() {
+                Self::consume(parts, index, rest, consumed);
+            }
+        } else if let Some((segment, rest)) = segments.split_first() {
+            if part.matches(segment) {
+                Self::consume(parts, index + 1, rest, consumed);
             }
         }
-        return true;
-    }
-    
-    // fn import_match
-    //Namespace Match
-    //Part Match
-    //Regex Match
-    //???
+    }
 }
 
 impl SearchPart {
-    fn matches(&self, match_string: String) -> bool {
+    fn new(part: &str) -> anyhow::Result<SearchPart, Error> {
+        let pattern = if part == "**" {
+            None
+        } else if let Some(regex) = part.strip_prefix('/').and_then(|p| p.strip_suffix('/')) {
+            Some(regex.replace("\\/", "/"))
+        } else if part.contains(['(', ')']) {
+            // only a signature at the end of the query has parentheses outside a regular expression
+            return Err(anyhow!("unbalanced parentheses in segment '{}' of query", part));
+        } else if part.contains(['*', '?']) {
+            let glob: String = part.chars().map(|c| match c {
+                '*' => ".*".to_string(),
+                '?' => ".".to_string(),
+                c => regex::escape(&c.to_string()),
+            }).collect();
+            Some(glob)
+        } else {
+            None
+        };
+        let regex = match pattern {
+            None => None,
+            Some(pattern) => Some(
+                Regex::new(&format!("^(?:{})$", pattern))
+                    .with_context(|| format!("invalid pattern '{}' in query", part))?,
+            ),
+        };
+        Ok(SearchPart{
+            part: part.to_string(),
+            regex,
+        })
+    }
+
+    /// Whether this is a `**` part, matching any number of namespace segments.
+    fn is_any_segments(&self) -> bool {
+        self.part == "**"
+    }
+
+    fn matches(&self, match_string: &str) -> bool {
         match &self.regex {
-            None => return self.part == match_string,
-            Some(r) => return r.is_match(match_string.as_str()),
+            None => self.part == match_string,
+            Some(r) => r.is_match(match_string),
         }
     }
-}
\ No newline at end of file
+}
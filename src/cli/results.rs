@@ -1,23 +1,110 @@
-use std::{any::Any, collections::HashMap};
+use std::{collections::BTreeMap, fmt, io::Write};
 
+use clap::ValueEnum;
+use serde::Serialize;
 
-#[derive(Debug)]
+
+/// A single incident found by a query.
+///
+/// `line_number` is 1-based, while the positions in `code_location` are 0-based, the same as
+/// LSP positions. Field names are serialized the way konveyor incidents spell them.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Result {
+    #[serde(rename = "fileURI")]
     pub file_uri: String,
     pub line_number: usize,
-    pub variables: HashMap<String, Box<dyn Any>>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub variables: BTreeMap<String, Variable>,
     pub code_location: Location,
 
 }
 
-#[derive(Debug)]
+/// A typed value attached to a result.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum Variable {
+    Bool(bool),
+    Integer(i64),
+    String(String),
+    List(Vec<Variable>),
+    Map(BTreeMap<String, Variable>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
 pub struct Position {
     pub line: usize,
     pub character: usize, 
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Location {
     pub start_position: Position,
     pub end_position: Position,
-}
\ No newline at end of file
+}
+
+/// The formats results can be written in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// One tab separated line per result.
+    #[default]
+    Text,
+    /// A single JSON array.
+    Json,
+    /// One JSON object per line.
+    Jsonl,
+    /// A YAML sequence.
+    Yaml,
+}
+
+impl Result {
+    /// The key results are ordered and de-duplicated by.
+    pub fn sort_key(&self) -> (&str, Location) {
+        (&self.file_uri, self.code_location)
+    }
+}
+
+/// Writes `results` to `w` in the given format.
+pub fn write_results<W: Write>(results: &[Result], format: OutputFormat, mut w: W) -> anyhow::Result<()> {
+    match format {
+        OutputFormat::Text => {
+            for result in results {
+                writeln!(w, "{}", result)?;
+            }
+        }
+        OutputFormat::Json => {
+            serde_json::to_writer_pretty(&mut w, results)?;
+            writeln!(w)?;
+        }
+        OutputFormat::Jsonl => {
+            for result in results {
+                serde_json::to_writer(&mut w, result)?;
+                writeln!(w)?;
+            }
+        }
+        OutputFormat::Yaml => {
+            serde_yaml::to_writer(&mut w, results)?;
+        }
+    }
+    Ok(())
+}
+
+/// Renders the result as a single tab separated line: `FILE_URI  LINE_NUMBER  START-END`.
+impl fmt::Display for Result {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}\t{}\t{}", self.file_uri, self.line_number, self.code_location)
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.start_position, self.end_position)
+    }
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.character)
+    }
+}
@@ -0,0 +1,295 @@
+use std::sync::OnceLock;
+
+use regex::Regex;
+
+/// The parameters of a method definition, parsed from its parameter list, such as
+/// `(string key, int count = 0)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Parameters {
+    parameters: Vec<Parameter>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Parameter {
+    type_name: String,
+    /// Whether the parameter has a default value.
+    optional: bool,
+    /// Whether this is a `params` array, which takes any number of arguments.
+    params: bool,
+}
+
+/// The arguments of an invocation, with the types that can be told from the arguments
+/// themselves.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Arguments {
+    types: Vec<Option<String>>,
+    /// Whether arguments are passed by name, so that their position says nothing about the
+    /// parameter they are for.
+    named: bool,
+}
+
+/// What the syntax tree tells about an argument of an invocation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Argument {
+    /// An argument whose type is not known, such as a variable, a call or an argument passed by
+    /// `ref`, `out` or `in`.
+    Unknown,
+    /// An argument passed by name.
+    Named,
+    /// An argument whose type is written out, such as a string literal or a `new` expression.
+    Typed(String),
+    /// A numeric literal, whose type is told by its form and suffix.
+    Number(String),
+}
+
+const MODIFIERS: &[&str] = &["this", "ref", "out", "in", "params", "scoped", "readonly"];
+
+const NUMERIC_TYPES: &[&str] = &[
+    "sbyte", "byte", "short", "ushort", "int", "uint", "long", "ulong", "float", "double", "decimal",
+];
+
+impl Parameters {
+    pub fn parse(text: &str) -> Parameters {
+        let parameters = split_list(text).into_iter().map(|parameter| {
+            let parameter = strip_attributes(parameter);
+            let (declaration, default) = match split_top_level(parameter, '=').as_slice() {
+                [declaration] => (*declaration, false),
+                [declaration, ..] => (*declaration, true),
+                [] => ("", false),
+            };
+            let mut tokens = split_top_level(declaration, ' ');
+            tokens.retain(|token| !token.is_empty());
+            let params = tokens.first() == Some(&"params");
+            tokens.retain(|token| !MODIFIERS.contains(token));
+            // The last token is the name of the parameter, the ones before it its type.
+            tokens.pop();
+            Parameter {
+                type_name: normalize_type(&tokens.join(" ")),
+                optional: default,
+                params,
+            }
+        }).collect();
+        Parameters{parameters}
+    }
+
+    /// Whether the parameters have exactly the types of a query signature. A type without a `?`
+    /// in the signature matches the nullable type as well, so `object` matches `object?`.
+    pub fn matches(&self, types: &[String]) -> bool {
+        self.parameters.len() == types.len()
+            && self.parameters.iter().zip(types).all(|(parameter, type_name)| {
+                &parameter.type_name == type_name
+                    || parameter.type_name.strip_suffix('?') == Some(type_name.as_str())
+            })
+    }
+
+    /// Whether both parameter lists have the same types, as an overriding method and the method
+    /// it overrides do.
+    pub fn same_types(&self, other: &Parameters) -> bool {
+        self.parameters.len() == other.parameters.len()
+            && self.parameters.iter().zip(&other.parameters).all(|(a, b)| a.type_name == b.type_name)
+    }
+
+    /// Whether an invocation with `arguments` can call a method with these parameters. The number
+    /// of arguments must fit, and every argument whose type is known must convert to its
+    /// parameter's type.
+    pub fn accepts(&self, arguments: &Arguments) -> bool {
+        let count = arguments.types.len();
+        let required = self.parameters.iter().filter(|p| !p.optional && !p.params).count();
+        let variadic = self.parameters.last().is_some_and(|p| p.params);
+        if count < required || (count > self.parameters.len() && !variadic) {
+            return false;
+        }
+        if arguments.named {
+            return true;
+        }
+        arguments.types.iter().enumerate().all(|(i, argument)| {
+            let argument = match argument {
+                None => return true,
+                Some(argument) => argument,
+            };
+            let parameter = match self.parameters.get(i).or(self.parameters.last()) {
+                None => return false,
+                Some(parameter) => parameter,
+            };
+            if parameter.params {
+                // A `params` array takes either an array or its elements.
+                let element = parameter.type_name.strip_suffix("[]").unwrap_or(&parameter.type_name);
+                converts_to(argument, element) || converts_to(argument, &parameter.type_name)
+            } else {
+                converts_to(argument, &parameter.type_name)
+            }
+        })
+    }
+}
+
+impl Arguments {
+    pub fn new(arguments: &[Argument]) -> Arguments {
+        let named = arguments.contains(&Argument::Named);
+        let types = arguments.iter().map(|argument| match argument {
+            Argument::Unknown | Argument::Named => None,
+            Argument::Typed(type_name) => Some(normalize_type(type_name)),
+            Argument::Number(literal) => Some(number_type(literal)),
+        }).collect();
+        Arguments{types, named}
+    }
+}
+
+/// Parses the signature of a query, such as `(string, int)`, into normalized type names.
+pub fn parse_signature(text: &str) -> Vec<String> {
+    split_list(text).into_iter().map(normalize_type).collect()
+}
+
+/// Splits a parenthesized list, such as a parameter or argument list, into its elements.
+fn split_list(text: &str) -> Vec<&str> {
+    let text = text.trim();
+    let text = text.strip_prefix(['(', '[']).unwrap_or(text);
+    let text = text.strip_suffix([')', ']']).unwrap_or(text);
+    if text.trim().is_empty() {
+        return vec![];
+    }
+    split_top_level(text, ',').into_iter().map(str::trim).collect()
+}
+
+/// Splits `text` at every `separator` that is not nested in brackets or in a literal. Every `<`
+/// and `>` is taken for a bracket, as it is in a type.
+fn split_top_level(text: &str, separator: char) -> Vec<&str> {
+    let mut parts = vec![];
+    let mut depth = 0;
+    let mut quote: Option<char> = None;
+    let mut escaped = false;
+    let mut start = 0;
+    let mut previous = '\0';
+    for (i, c) in text.char_indices() {
+        if let Some(q) = quote {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == q {
+                quote = None;
+            }
+        } else {
+            match c {
+                '"' | '\'' => quote = Some(c),
+                '(' | '[' | '{' | '<' => depth += 1,
+                ')' | ']' | '}' | '>' => depth -= 1,
+                // `==` is an operator, not the start of a default value.
+                '=' if separator == '=' && text[i + 1..].starts_with('=') => {}
+                _ if c == separator && depth == 0 && !(c == '=' && "=!<>".contains(previous)) => {
+                    parts.push(&text[start..i]);
+                    start = i + c.len_utf8();
+                }
+                _ => {}
+            }
+        }
+        previous = c;
+    }
+    parts.push(&text[start..]);
+    parts.into_iter().map(str::trim).collect()
+}
+
+/// Removes the attribute lists in front of a parameter.
+fn strip_attributes(parameter: &str) -> &str {
+    let mut parameter = parameter.trim();
+    while parameter.starts_with('[') {
+        let mut depth = 0;
+        let end = parameter.char_indices().find_map(|(i, c)| {
+            match c {
+                '[' => depth += 1,
+                ']' => depth -= 1,
+                _ => {}
+            }
+            if depth == 0 { Some(i + 1) } else { None }
+        });
+        match end {
+            None => break,
+            Some(end) => parameter = parameter[end..].trim_start(),
+        }
+    }
+    parameter
+}
+
+/// The type of a numeric literal, from its form and suffix.
+fn number_type(literal: &str) -> String {
+    let literal = literal.to_ascii_lowercase().replace('_', "");
+    let hex = literal.starts_with("0x") || literal.starts_with("0b");
+    let type_name = if literal.ends_with("ul") || literal.ends_with("lu") {
+        "ulong"
+    } else if literal.ends_with('l') {
+        "long"
+    } else if literal.ends_with('u') {
+        "uint"
+    } else if !hex && literal.ends_with('f') {
+        "float"
+    } else if literal.ends_with('m') {
+        "decimal"
+    } else if !hex && (literal.ends_with('d') || literal.contains(['.', 'e'])) {
+        "double"
+    } else {
+        "int"
+    };
+    type_name.to_string()
+}
+
+/// Whether a value of type `argument` converts implicitly to type `parameter`. Conversions
+/// between built-in types follow C#'s rules, and built-in types convert to no arrays; any other
+/// type may have conversions that cannot be seen here, so is assumed to accept the argument.
+fn converts_to(argument: &str, parameter: &str) -> bool {
+    let parameter = parameter.strip_suffix('?').unwrap_or(parameter);
+    if argument == parameter || parameter == "object" || parameter == "dynamic" {
+        return true;
+    }
+    let widening: &[&str] = match argument {
+        // Constant integers convert to any numeric type they fit in.
+        "int" => NUMERIC_TYPES,
+        "uint" => &["long", "ulong", "float", "double", "decimal"],
+        "long" => &["float", "double", "decimal"],
+        "ulong" => &["float", "double", "decimal"],
+        "char" => &["ushort", "int", "uint", "long", "ulong", "float", "double", "decimal"],
+        "float" => &["double"],
+        "double" | "decimal" | "bool" | "string" => &[],
+        _ => return true,
+    };
+    widening.contains(&parameter) || !(is_builtin_type(parameter) || parameter.ends_with(']'))
+}
+
+fn is_builtin_type(type_name: &str) -> bool {
+    NUMERIC_TYPES.contains(&type_name) || ["bool", "char", "string", "object"].contains(&type_name)
+}
+
+/// Normalizes a type name so that different spellings of the same type compare equal: namespace
+/// qualifiers and whitespace are dropped, and framework names of built-in types are replaced by
+/// their keywords, also in type arguments, so `System.String` and `string` are the same.
+fn normalize_type(type_name: &str) -> String {
+    static QUALIFIERS: OnceLock<Regex> = OnceLock::new();
+    static NAMES: OnceLock<Regex> = OnceLock::new();
+    let qualifiers = QUALIFIERS.get_or_init(|| {
+        Regex::new(r"(?:global::)?\b(?:[A-Za-z_@][A-Za-z0-9_]*\s*\.\s*)+").unwrap()
+    });
+    let names = NAMES.get_or_init(|| Regex::new(r"[A-Za-z_@][A-Za-z0-9_]*").unwrap());
+    let type_name = qualifiers.replace_all(type_name.trim(), "");
+    let type_name: String = type_name.chars().filter(|c| !c.is_whitespace()).collect();
+    names.replace_all(&type_name, |name: &regex::Captures| keyword(&name[0]).to_string()).into_owned()
+}
+
+/// The keyword of a built-in type with the framework name `name`, or the name itself.
+fn keyword(name: &str) -> &str {
+    match name {
+        "String" => "string",
+        "Object" => "object",
+        "Boolean" => "bool",
+        "Char" => "char",
+        "SByte" => "sbyte",
+        "Byte" => "byte",
+        "Int16" => "short",
+        "UInt16" => "ushort",
+        "Int32" => "int",
+        "UInt32" => "uint",
+        "Int64" => "long",
+        "UInt64" => "ulong",
+        "Single" => "float",
+        "Double" => "double",
+        "Decimal" => "decimal",
+        other => other,
+    }
+}
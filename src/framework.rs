@@ -0,0 +1,58 @@
+const FRAMEWORK_VERSIONS: &[&str] = &["20", "35", "40", "45", "451", "452", "46", "461", "462", "47", "471", "472", "48", "481"];
+const CORE_VERSIONS: &[&str] = &["1.0", "1.1", "2.0", "2.1", "2.2", "3.0", "3.1"];
+const STANDARD_VERSIONS: &[&str] = &["1.0", "1.1", "1.2", "1.3", "1.4", "1.5", "1.6", "2.0", "2.1"];
+
+/// The preprocessor symbols that are defined when compiling for a target framework, such as
+/// `net48`, `net6.0`, `netcoreapp3.1` or `netstandard2.0`, or `None` if the moniker is not one of
+/// those. Like the .NET SDK, every framework defines a symbol for the family, one for the version,
+/// and an `_OR_GREATER` symbol for the version and every version of the family before it.
+pub fn define_constants(target_framework: &str) -> Option<Vec<String>> {
+    // an operating system suffix, as in `net8.0-windows`, does not change the framework
+    let moniker = target_framework.trim().to_ascii_lowercase();
+    let moniker = moniker.split('-').next().unwrap_or_default();
+
+    if let Some(version) = moniker.strip_prefix("netstandard") {
+        return family("NETSTANDARD", version, STANDARD_VERSIONS);
+    }
+    if let Some(version) = moniker.strip_prefix("netcoreapp") {
+        return family("NETCOREAPP", version, CORE_VERSIONS);
+    }
+    let version = moniker.strip_prefix("net")?;
+    if version.contains('.') {
+        // .NET 5 and later continue .NET Core under the name .NET
+        let major: u32 = version.split('.').next()?.parse().ok()?;
+        if major < 5 {
+            return None;
+        }
+        let versions: Vec<String> = (5..=major).map(|major| format!("{}.0", major)).collect();
+        let versions: Vec<&str> = versions.iter().map(String::as_str).collect();
+        let mut constants = family("NET", version, &versions)?;
+        constants.push("NETCOREAPP".to_string());
+        constants.extend(CORE_VERSIONS.iter().map(|version| format!("NETCOREAPP{}_OR_GREATER", symbol(version))));
+        Some(constants)
+    } else {
+        let mut constants = family("NET", version, FRAMEWORK_VERSIONS)?;
+        constants[0] = "NETFRAMEWORK".to_string();
+        Some(constants)
+    }
+}
+
+/// The symbols of a framework family: the family, the version, and the `_OR_GREATER` symbols of
+/// the known versions up to the version.
+fn family(name: &str, version: &str, versions: &[&str]) -> Option<Vec<String>> {
+    if !versions.contains(&version) {
+        return None;
+    }
+    let mut constants = vec![name.to_string(), format!("{}{}", name, symbol(version))];
+    constants.extend(
+        versions.iter()
+            .take_while(|known| **known != version)
+            .chain(std::iter::once(&version))
+            .map(|known| format!("{}{}_OR_GREATER", name, symbol(known))),
+    );
+    Some(constants)
+}
+
+fn symbol(version: &str) -> String {
+    version.replace('.', "_")
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 pub mod cli;
+pub mod framework;
 use tree_sitter_stack_graphs::loader::LanguageConfiguration;
 use tree_sitter_stack_graphs::loader::LoadError;
 use tree_sitter_stack_graphs::CancellationFlag;
@@ -32,9 +33,56 @@ pub fn language_configuration(cancellation_flag: &dyn CancellationFlag) -> Langu
     try_language_configuration(cancellation_flag).unwrap_or_else(|err| panic!("{}", err))
 }
 
+#[allow(clippy::result_large_err)]
 pub fn try_language_configuration(
     cancellation_flag: &dyn CancellationFlag,
-) -> Result<LanguageConfiguration, LoadError> {
+) -> Result<LanguageConfiguration, LoadError<'_>> {
+    try_language_configuration_with_builtins_config(STACK_GRAPHS_BUILTINS_CONFIG, cancellation_flag)
+}
+
+/// The language configuration with the builtins built for a target framework moniker, such as
+/// `net48`, `net6.0` or `net8.0`, so that only the API surface of that framework is active.
+pub fn language_configuration_for_framework(
+    target_framework: &str,
+    cancellation_flag: &dyn CancellationFlag,
+) -> LanguageConfiguration {
+    try_language_configuration_for_framework(target_framework, cancellation_flag)
+        .unwrap_or_else(|err| panic!("{}", err))
+}
+
+#[allow(clippy::result_large_err)]
+pub fn try_language_configuration_for_framework<'a>(
+    target_framework: &str,
+    cancellation_flag: &'a dyn CancellationFlag,
+) -> Result<LanguageConfiguration, LoadError<'a>> {
+    let builtins_config = builtins_config_for_framework(target_framework)?;
+    try_language_configuration_with_builtins_config(&builtins_config, cancellation_flag)
+}
+
+/// The builtins configuration with the `TARGET_FRAMEWORK` and `DEFINE_CONSTANTS` globals set for
+/// a target framework moniker.
+#[allow(clippy::result_large_err)]
+pub fn builtins_config_for_framework(target_framework: &str) -> Result<String, LoadError<'static>> {
+    let constants = framework::define_constants(target_framework).ok_or_else(|| {
+        LoadError::Reader(format!("Unknown target framework {}", target_framework).into())
+    })?;
+    let mut config = String::new();
+    for line in STACK_GRAPHS_BUILTINS_CONFIG.lines() {
+        match line.split_once('=').map(|(name, _)| name.trim()) {
+            Some("TARGET_FRAMEWORK") => config.push_str(&format!("TARGET_FRAMEWORK = {}", target_framework)),
+            Some("DEFINE_CONSTANTS") => config.push_str(&format!("DEFINE_CONSTANTS = ;{};", constants.join(";"))),
+            _ => config.push_str(line),
+        }
+        config.push('\n');
+    }
+    Ok(config)
+}
+
+#[allow(clippy::result_large_err)]
+fn try_language_configuration_with_builtins_config<'a>(
+    builtins_config: &str,
+    cancellation_flag: &'a dyn CancellationFlag,
+) -> Result<LanguageConfiguration, LoadError<'a>> {
     LanguageConfiguration::from_sources(
         tree_sitter_c_sharp::LANGUAGE.into(),
         Some(String::from("source.cs")),
@@ -46,7 +94,7 @@ pub fn try_language_configuration(
             STACK_GRAPHS_BUILTINS_PATH.into(),
             STACK_GRAPHS_BUILTINS_SOURCE,
         )),
-        Some(STACK_GRAPHS_BUILTINS_CONFIG),
+        Some(builtins_config),
         cancellation_flag,
     )
 }
@@ -17,7 +17,7 @@ use clap::Parser;
 use clap::Subcommand;
 use tree_sitter_stack_graphs::cli::provided_languages::Clean;
 use tree_sitter_stack_graphs::cli::provided_languages::Visualize;
-use tree_sitter_stack_graphs::cli::provided_languages::Index;
+use tree_sitter_stack_graphs::cli::provided_languages::Query;
 use tree_sitter_stack_graphs::cli::provided_languages::Status;
 use tree_sitter_stack_graphs::loader::LanguageConfiguration;
 use std::path::PathBuf;
@@ -25,24 +25,39 @@ use tree_sitter_stack_graphs::cli::database::default_user_database_path_for_crat
 use tree_sitter_stack_graphs::NoCancellation;
 
 use tree_sitter_stack_graphs_c_sharp::cli::find_node::FindNode;
+use tree_sitter_stack_graphs_c_sharp::cli::index::Index;
+use tree_sitter_stack_graphs_c_sharp::cli::index_external::IndexExternal;
 
 fn main() -> anyhow::Result<()> {
-    let lc = match tree_sitter_stack_graphs_c_sharp::try_language_configuration(&NoCancellation)
-    {
-        Ok(lc) => lc,
+    let cli = Cli::parse();
+    let default_db_path = default_user_database_path_for_crate(env!("CARGO_PKG_NAME"))?;
+    cli.subcommand.run(default_db_path, cli.framework.as_deref())
+}
+
+/// The language configuration for a target framework, or for every framework. Building its
+/// builtins takes a while, so only the subcommands that index or resolve references build it.
+fn language_configurations(framework: Option<&str>) -> anyhow::Result<Vec<LanguageConfiguration>> {
+    let lc = match framework {
+        Some(framework) => tree_sitter_stack_graphs_c_sharp::try_language_configuration_for_framework(framework, &NoCancellation),
+        None => tree_sitter_stack_graphs_c_sharp::try_language_configuration(&NoCancellation),
+    };
+    match lc {
+        Ok(lc) => Ok(vec![lc]),
         Err(err) => {
             eprintln!("{}", err.display_pretty());
-            return Err(anyhow!("Language configuration error"));
+            Err(anyhow!("Language configuration error"))
         }
-    };
-    let cli = Cli::parse();
-    let default_db_path = default_user_database_path_for_crate(env!("CARGO_PKG_NAME"))?;
-    cli.subcommand.run(default_db_path, vec![lc])
+    }
 }
 
 #[derive(Parser)]
 #[clap(about, version)]
 pub struct Cli {
+    /// Target framework moniker, such as `net48`, `net6.0` or `net8.0`, that selects which API
+    /// surface of the .NET class library references resolve to. Without it, the API surface of
+    /// every framework is active.
+    #[clap(long, global = true)]
+    framework: Option<String>,
     #[clap(subcommand)]
     subcommand: ExtendedSubcommands,
 }
@@ -51,19 +66,23 @@ pub struct Cli {
 pub enum ExtendedSubcommands{
     Clean(Clean),
     Index(Index),
+    Query(Query),
     Status(Status),
     Visualize(Visualize),
-    FindNode(FindNode)
+    FindNode(FindNode),
+    IndexExternal(IndexExternal),
 }
 
 impl ExtendedSubcommands {
-    pub fn run(self, default_db_path: PathBuf, config: Vec<LanguageConfiguration>) -> anyhow::Result<()> {
+    pub fn run(self, default_db_path: PathBuf, framework: Option<&str>) -> anyhow::Result<()> {
         match self {
             Self::Clean(cmd) => cmd.run(default_db_path),
-            Self::Index(cmd) => cmd.run(default_db_path, config),
+            Self::Index(cmd) => cmd.run(default_db_path, language_configurations(framework)?),
+            Self::Query(cmd) => cmd.run(default_db_path),
             Self::Status(cmd) => cmd.run(default_db_path),
             Self::Visualize(cmd) => cmd.run(default_db_path),
-            Self::FindNode(cmd) => cmd.run(default_db_path),
+            Self::FindNode(cmd) => cmd.run(default_db_path, language_configurations(framework)?),
+            Self::IndexExternal(cmd) => cmd.run(default_db_path, language_configurations(framework)?),
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,29 @@
+using System.Collections.Generic;
+
+namespace App
+{
+    class Table
+    {
+        int this[int row] => row;
+        int this[int row, int column] => row + column;
+
+        int Count(int value) => value;
+
+        void Run(int[] values, int[,] grid, Dictionary<string, int> map, Table other)
+        {
+            // arguments of element accesses, tuples, initializers and collection expressions are
+            // not arguments of invocations
+            var first = values[0];
+            var cell = grid[0, 1];
+            var entry = map["k"];
+            var own = this[1];
+            var maybe = other?[0];
+            var pair = (1, 2);
+            var point = (x: 1, y: 2);
+            var initialized = new Dictionary<string, int> { ["a"] = 1 };
+            int[] collected = [1, 2];
+            var counted = Count(values[0]);
+            //            ^ defined: 10
+        }
+    }
+}
@@ -0,0 +1,25 @@
+namespace Lib
+{
+    class ObsoleteAttribute : System.Attribute { }
+    class Serializable : System.Attribute { }
+}
+
+namespace App
+{
+    using Lib;
+
+    [Obsolete]
+    // ^ defined: 3
+    class Old
+    {
+        [Lib.Obsolete, Serializable]
+        //   ^ defined: 3
+        //             ^ defined: 4
+        int field;
+
+        [ObsoleteAttribute()]
+        // ^ defined: 3
+        void Run([Serializable] int value) { }
+        //         ^ defined: 4
+    }
+}
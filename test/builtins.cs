@@ -0,0 +1,42 @@
+using System;
+using System.IO;
+
+namespace App
+{
+    public class Path
+    {
+        public static string Combine(string first, string second)
+        {
+            return first + second;
+        }
+    }
+
+    public class AppException : Exception
+    {
+        public string Code;
+
+        public AppException(string code) : base(code)
+        {
+            Code = code;
+            //     ^ defined: 18
+            // <- defined: 16
+        }
+    }
+
+    public class Runner
+    {
+        public void Run(string name)
+        {
+            var joined = Path.Combine(name, "b");
+            //           ^ defined: 6
+            //                ^ defined: 8
+            //                        ^ defined: 28
+            var error = new AppException(joined);
+            //              ^ defined: 14
+            //                           ^ defined: 30
+            Console.WriteLine(error.Code);
+            //                ^ defined: 34
+            //                      ^ defined: 16
+        }
+    }
+}
@@ -0,0 +1,54 @@
+namespace Shapes
+{
+    class Circle
+    {
+        public double Radius { get; set; }
+        public double Area() { return 0; }
+    }
+
+    record Point(int X, int Y);
+
+    class Canvas
+    {
+#if DEBUG
+        void Trace() { }
+#else
+        void Trace(int level) { }
+#endif
+
+        double Measure(object shape, Point origin)
+        {
+            if (shape is Circle circle)
+            //           ^ defined: 3
+            {
+                Trace();
+                // <- defined: 14, 16
+                return circle.Area();
+                //     ^ defined: 21
+                //            ^ defined: 6
+            }
+            var moved = origin with { X = 1 };
+            //          ^ defined: 19
+            //                        ^ defined: 9
+            var created = new Circle { Radius = 2 };
+            //                         ^ defined: 5
+            Parse("1", out var parsed);
+            var size = shape switch
+            {
+                Circle { Radius: > 1 } big => big.Radius,
+                //       ^ defined: 5
+                //                            ^ defined: 38
+                //                                ^ defined: 5
+                Point p => p.X,
+                //         ^ defined: 42
+                //           ^ defined: 9
+                _ => default(double),
+            };
+            return parsed + $"{size}".Length;
+            //     ^ defined: 35
+            //                 ^ defined: 36
+        }
+
+        bool Parse(string text, out int value) { value = 0; return true; }
+    }
+}
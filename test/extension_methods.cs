@@ -0,0 +1,47 @@
+// --- path: Extensions.cs ---
+namespace Text.Extensions
+{
+    public static class StringExtensions
+    {
+        public static string Shout(this string text) => text;
+        public static string Whisper(string text) => text;
+        public static int Length(this string text) => 0;
+    }
+}
+// --- path: Global.cs ---
+public static class GlobalExtensions
+{
+    public static void Log(this object value) { }
+}
+// --- path: Program.cs ---
+using Text.Extensions;
+
+namespace App
+{
+    class Program
+    {
+        void Run(string name)
+        {
+            name.Shout();
+            //   ^ defined: 6
+            name.Whisper();
+            //   ^ defined:
+            name.Log();
+            //   ^ defined: 14
+            StringExtensions.Shout(name);
+            //               ^ defined: 6
+        }
+    }
+}
+// --- path: Other.cs ---
+namespace Other
+{
+    class Program
+    {
+        void Run(string name)
+        {
+            name.Shout();
+            //   ^ defined:
+        }
+    }
+}
@@ -0,0 +1,33 @@
+// --- path: Settings.cs ---
+namespace Lib.Configuration;
+
+public class Settings
+{
+    public static string Get(string key) { return Default(key); }
+    //                                            ^ defined: 9
+
+    static string Default(string key) { return key; }
+}
+// --- path: Program.cs ---
+namespace App;
+
+using Lib.Configuration;
+//        ^ defined: 2
+
+class Program
+{
+    void Main()
+    {
+        Settings.Get("a");
+        // ^ defined: 4
+        //       ^ defined: 6
+        Lib.Configuration.Settings.Get("b");
+        //                ^ defined: 4
+        new Helper();
+        //  ^ defined: 31
+    }
+}
+
+class Helper
+{
+}
@@ -0,0 +1,49 @@
+namespace Lib
+{
+    interface IShape
+    {
+        double Area();
+    }
+
+    class Box<T> : IContainer<T> where T : IShape
+    //             ^ defined: 35
+    //                        ^ defined: 8
+    //                                 ^ defined: 8
+    //                                     ^ defined: 3
+    {
+        T item;
+        // <- defined: 8
+
+        double Measure()
+        {
+            return item.Area();
+            //     ^ defined: 14
+            //          ^ defined: 5
+        }
+
+        U Convert<U>(T value) where U : IShape
+        //           ^ defined: 8
+        //                          ^ defined: 24
+        // <- defined: 24
+        {
+            U result = default;
+            // <- defined: 24
+            return result;
+        }
+    }
+
+    interface IContainer<T> { }
+}
+
+namespace App
+{
+    using Lib;
+
+    class Runner
+    {
+        Box<IShape> boxes;
+        //  ^ defined: 3
+        // <- defined: 8
+    }
+}
+
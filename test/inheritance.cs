@@ -0,0 +1,44 @@
+namespace Web
+{
+    class Page
+    {
+        public object Response;
+        protected virtual void OnLoad() { }
+    }
+
+    interface IHandler
+    {
+        void Handle();
+    }
+}
+
+namespace App
+{
+    using Web;
+
+    class Home : Page, IHandler
+    {
+        public void Handle() { }
+
+        protected void OnInit()
+        {
+            var r = this.Response;
+            //           ^ defined: 5
+            base.OnLoad();
+            //   ^ defined: 6
+            Response.ToString();
+            // ^ defined: 5
+        }
+    }
+
+    class Child : Home
+    {
+        void Run()
+        {
+            this.OnInit();
+            //   ^ defined: 23
+            var r = Response;
+            //      ^ defined: 5
+        }
+    }
+}
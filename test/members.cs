@@ -0,0 +1,43 @@
+namespace Config
+{
+    class Settings
+    {
+        public const int Limit = 10;
+        private int count = Limit, total;
+        //                  ^ defined: 5
+
+        public string Name { get; set; }
+
+        public event System.EventHandler Changed;
+
+        public event System.EventHandler Saved
+        {
+            add { }
+            remove { }
+        }
+
+        void Update()
+        {
+            count = total + Limit;
+            // ^ defined: 6
+            //      ^ defined: 6
+            //              ^ defined: 5
+            Name = this.Name;
+            // ^ defined: 9
+            //          ^ defined: 9
+            Changed(this, null);
+            // ^ defined: 11
+            this.Saved += null;
+            //   ^ defined: 13
+        }
+    }
+
+    class Reader
+    {
+        string Read(Settings settings)
+        {
+            return Settings.Limit + settings.Name;
+            //              ^ defined: 5
+        }
+    }
+}
@@ -0,0 +1,41 @@
+// --- path: Outer.cs ---
+namespace Company
+{
+    class Shared
+    {
+    }
+
+    namespace Product
+    {
+        class Widget
+        {
+            Shared shared;
+            // ^ defined: 4
+        }
+    }
+}
+// --- path: Dotted.cs ---
+namespace Company.Product.Parts
+{
+    class Gear
+    {
+        Shared shared;
+        // ^ defined: 4
+        Widget widget;
+        // ^ defined: 10
+        Product.Widget qualified;
+        // ^ defined: 8, 18
+        //      ^ defined: 10
+    }
+}
+// --- path: Program.cs ---
+using Company.Product;
+//            ^ defined: 8, 18
+
+class Program
+{
+    Widget widget;
+    // ^ defined: 10
+    Company.Product.Parts.Gear gear;
+    //                    ^ defined: 20
+}
@@ -0,0 +1,14 @@
+namespace App
+{
+    class Greeter
+    {
+        public void Greet(string name) { }
+        public void Greet(int count, string name = "") { }
+
+        void Run()
+        {
+            Greet("a");
+            // ^ defined: 5, 6
+        }
+    }
+}
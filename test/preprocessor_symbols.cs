@@ -0,0 +1,48 @@
+// --- global: DEFINE_CONSTANTS=;NET;NET8_0;NET8_0_OR_GREATER; ---
+
+#if NETFRAMEWORK
+namespace Legacy
+{
+    class Handler { }
+}
+#endif
+
+namespace Modern
+{
+    class Options { }
+}
+
+class Host
+{
+#if NETFRAMEWORK
+    void Start() { }
+#elif NET8_0_OR_GREATER
+    void Start(string[] args) { }
+#else
+    void Start(int level) { }
+#endif
+
+#if !NET
+    int timeout;
+#else
+    long timeout;
+#endif
+
+#if NET && !NETFRAMEWORK
+    void Stop() { }
+#endif
+
+    void Run()
+    {
+        Start(null);
+        // ^ defined: 20
+        timeout = 1;
+        // ^ defined: 28
+        Stop();
+        // ^ defined: 32
+        Modern.Options options = null;
+        //     ^ defined: 12
+        Legacy.Handler handler = null;
+        //     ^ defined:
+    }
+}
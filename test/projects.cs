@@ -0,0 +1,40 @@
+// --- path: Shared.cs ---
+namespace Shared
+{
+    public class Clock { }
+}
+// --- path: Lib/Customer.cs ---
+// --- global: PROJECT_NAME=Lib ---
+namespace Lib.Models
+{
+    public class Customer
+    {
+        public Shared.Clock Clock;
+        //            ^ defined: 4
+    }
+}
+// --- path: App/Order.cs ---
+// --- global: PROJECT_NAME=App ---
+namespace App.Models
+{
+    public class Order { }
+}
+// --- path: App/Program.cs ---
+// --- global: PROJECT_NAME=App ---
+using App.Models;
+//        ^ defined: 18
+using Lib.Models;
+//        ^ defined:
+
+namespace App
+{
+    class Program
+    {
+        Order order;
+        // ^ defined: 20
+        Customer customer;
+        // ^ defined:
+        global::Shared.Clock clock;
+        //             ^ defined: 4
+    }
+}
@@ -0,0 +1,20 @@
+namespace System.Configuration {
+    public class ConfigurationManager {
+        public static string Get(string key) { return key; }
+    }
+}
+namespace App {
+    class Program {
+        void Main() {
+            System.Configuration.ConfigurationManager.Get("a");
+            // ^ defined: 1
+            //     ^ defined: 1
+            //                   ^ defined: 2
+            //                                        ^ defined: 3
+            global::System.Configuration.ConfigurationManager.Get("a");
+            //                                                ^ defined: 3
+            var l = new System.Collections.Generic.List<System.Configuration.ConfigurationManager>();
+            //                                                                ^ defined: 2
+        }
+    }
+}
@@ -0,0 +1,45 @@
+namespace Lib
+{
+    class Manager
+    {
+        public void Start() { }
+        public Manager Next;
+    }
+}
+
+namespace App
+{
+    using Lib;
+
+    class Runner
+    {
+        Manager field;
+        Manager Current { get; }
+
+        void Run(Manager param, Lib.Manager? other)
+        {
+            var created = new Manager();
+            created.Start();
+            //      ^ defined: 5
+            Manager declared = null;
+            declared.Start();
+            //       ^ defined: 5
+            param.Start();
+            //    ^ defined: 5
+            other.Start();
+            //    ^ defined: 5
+            field.Next.Start();
+            //    ^ defined: 6
+            //         ^ defined: 5
+            this.Current.Start();
+            //           ^ defined: 5
+            foreach (Manager each in list)
+            {
+                each.Start();
+                //   ^ defined: 5
+            }
+            created.ToString();
+            // ^ defined: 21
+        }
+    }
+}
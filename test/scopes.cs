@@ -0,0 +1,74 @@
+namespace Scopes
+{
+    class Counter
+    {
+        int count;
+
+        Counter(int start)
+        {
+            Reset(start);
+            //    ^ defined: 7
+        }
+
+        void Reset(int value)
+        {
+            int doubled = value * 2;
+            //            ^ defined: 13
+            Increment(doubled);
+            //        ^ defined: 15
+            this.Increment(value);
+            //   ^ defined: 43
+            for (int i = 0; i < value; i++)
+            {
+                Increment(i);
+                //        ^ defined: 21
+            }
+            foreach (var item in new Helper().Items(value))
+            //                       ^ defined: 57
+            {
+                Increment(item);
+                //        ^ defined: 26
+            }
+            try
+            {
+                Increment(1);
+            }
+            catch (System.Exception e)
+            {
+                Increment(e.HResult);
+                //        ^ defined: 36
+            }
+        }
+
+        void Increment(int by)
+        {
+            System.Func<int, int> twice = x => x + by;
+            //                                 ^ defined: 45
+            //                                     ^ defined: 43
+            int Local(int y) { return y + by; }
+            //                        ^ defined: 48
+            //                            ^ defined: 43
+            Local(twice(by));
+            // ^ defined: 48
+            //    ^ defined: 45
+        }
+    }
+
+    class Helper
+    {
+        public int[] Items(int n)
+        {
+            return Other.Make(n);
+            //     ^ defined: 70
+            //           ^ defined: 72
+        }
+    }
+}
+
+namespace Scopes
+{
+    class Other
+    {
+        public static int[] Make(int n) { return new int[n]; }
+    }
+}
@@ -0,0 +1,30 @@
+namespace Shop
+{
+    class Money { }
+    class Item { }
+    class Handler { }
+
+    delegate Money Pricing(Item item);
+    //       ^ defined: 3
+
+    class Cart<T> where T : Item
+    //                      ^ defined: 4
+    {
+        Money Total { get; set; }
+        //^ defined: 3
+        event Handler Changed { add { } remove { } }
+        //    ^ defined: 5
+        Item this[int index] => null;
+        //^ defined: 4
+        public static Money operator +(Cart<T> cart, Money money) => money;
+        //            ^ defined: 3
+        public static explicit operator Money(Cart<T> cart) => null;
+        //                              ^ defined: 3
+
+        void Run()
+        {
+            Money Sum(Item item) => null;
+            //^ defined: 3
+        }
+    }
+}
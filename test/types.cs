@@ -0,0 +1,49 @@
+namespace Shapes
+{
+    interface IShape
+    {
+        double Area();
+    }
+
+    struct Point
+    {
+        int x;
+
+        Point Offset(Point by) { return by; }
+        //                              ^ defined: 12
+    }
+
+    enum Color
+    {
+        Red,
+        Green = Red,
+        //      ^ defined: 18
+    }
+
+    record Circle(Point Center, double Radius)
+    {
+        double Diameter() { return Radius * 2; }
+        //                         ^ defined: 23
+    }
+
+    class Canvas
+    {
+        IShape shape;
+        // ^ defined: 3
+        Point origin;
+        // ^ defined: 8
+        Color background = Color.Green;
+        // ^ defined: 16
+        //                       ^ defined: 19
+        Circle circle;
+        // ^ defined: 23
+
+        void Draw(Circle c)
+        {
+            var area = shape.Area();
+            var center = c.Center;
+            Circle.Equals(c, c);
+            // ^ defined: 23
+        }
+    }
+}
@@ -0,0 +1,47 @@
+// --- path: Lib.cs ---
+namespace Lib.Config
+{
+    class Manager
+    {
+        public static void Start() { }
+    }
+}
+
+namespace Lib.Util
+{
+    static class Helpers
+    {
+        public static void Log() { }
+    }
+}
+
+// --- path: Globals.cs ---
+global using Lib.Util;
+global using Mgr = Lib.Config.Manager;
+
+// --- path: App.cs ---
+using Cfg = Lib.Config;
+using static Lib.Util.Helpers;
+
+namespace App
+{
+    using M = Lib.Config.Manager;
+
+    class Runner
+    {
+        void Run()
+        {
+            Cfg.Manager.Start();
+            //  ^ defined: 4
+            //          ^ defined: 6
+            M.Start();
+            //^ defined: 6
+            Log();
+            //^ defined: 14
+            Mgr.Start();
+            //  ^ defined: 6
+            Helpers.Log();
+            // ^ defined: 12
+        }
+    }
+}
@@ -0,0 +1,39 @@
+// --- path: Settings.cs ---
+namespace Lib.Configuration
+{
+    public class Settings
+    {
+        public static string Get(string key) { return key; }
+    }
+}
+// --- path: Loader.cs ---
+namespace Lib.Configuration
+{
+    public class Loader
+    {
+    }
+}
+// --- path: Program.cs ---
+using Lib.Configuration;
+//    ^ defined: 2, 10
+//        ^ defined: 2, 10
+
+namespace App
+{
+    using Lib;
+    //    ^ defined: 2, 10
+
+    class Program
+    {
+        void Main()
+        {
+            Settings.Get("a");
+            // ^ defined: 4
+            //       ^ defined: 6
+            var loader = new Loader();
+            //               ^ defined: 12
+            Configuration.Settings.Get("b");
+            // ^ defined: 2, 10
+        }
+    }
+}